@@ -0,0 +1,28 @@
+#![no_main]
+use adventofcode2019::differential::{check, generate, Journaled, Stepwise};
+use adventofcode2019::rng::Rng;
+use libfuzzer_sys::fuzz_target;
+use std::panic;
+use std::process;
+use std::sync::Once;
+
+static QUIET: Once = Once::new();
+
+fuzz_target!(|data: &[u8]| {
+    // Overflowing programs panic on every backend and are compared as such,
+    // so the panic hook installed by libFuzzer must not abort on them.
+    QUIET.call_once(|| panic::set_hook(Box::new(|_| ())));
+
+    // Every backend here runs the same `IntcodeComputer::step`, so this only
+    // checks that `execute`, a bare `step` loop and a journal rewind and
+    // replay agree with each other, not that the interpreter is right.
+    // Compiled code has to be compiled ahead of time and only gets the fixed
+    // seeds of `aot::tests::GENERATED`, checked by the library tests.
+    let case = generate(&mut Rng::from_bytes(data));
+    let mut stepwise = Stepwise::default();
+    let mut journaled = Journaled::default();
+    if let Err(mismatch) = check(&case, &mut [&mut stepwise, &mut journaled]) {
+        eprintln!("{}", mismatch);
+        process::abort();
+    }
+});
@@ -0,0 +1,425 @@
+use crate::cfg::{ControlFlowGraph, Instruction};
+use crate::intcode::{Mode, Opcode};
+
+fn operand(ins: &Instruction, i: usize) -> String {
+    let param = ins.params[i];
+    match ins.modes[i] {
+        Mode::Positional => format!("*vm.memory.entry({}).or_insert(0)", param as usize),
+        Mode::Relative => format!("*vm.memory.entry((rb + {}) as usize).or_insert(0)", param),
+        _ => format!("{}", param),
+    }
+}
+
+fn store(ins: &Instruction, value: &str, out: &mut Vec<String>) {
+    let i = ins.params.len() - 1;
+    let param = ins.params[i];
+    if ins.modes[i] == Mode::Relative {
+        out.push(format!("let addr = (rb + {}) as usize;", param));
+        out.push(format!("vm.memory.insert(addr, {});", value));
+        // a relative store may land on compiled code
+        out.push("if addr < CODE.len() && COMPILED[addr] {".to_string());
+        out.push(format!(
+            "    return interpret(vm, {}, rb, steps, &stdin[pos..], stdout);",
+            ins.next_addr()
+        ));
+        out.push("}".to_string());
+    } else {
+        out.push(format!("vm.memory.insert({}, {});", param as usize, value));
+    }
+}
+
+fn translate(ins: &Instruction, out: &mut Vec<String>) {
+    let binary = |op: &str, out: &mut Vec<String>| {
+        out.push(format!("let a: i64 = {};", operand(ins, 0)));
+        out.push(format!("let b: i64 = {};", operand(ins, 1)));
+        out.push("steps += 1;".to_string());
+        store(ins, op, out);
+    };
+    match ins.opcode {
+        Opcode::Add => binary("a + b", out),
+        Opcode::Mul => binary("a * b", out),
+        Opcode::LessThan => binary("(a < b) as i64", out),
+        Opcode::Equals => binary("(a == b) as i64", out),
+        Opcode::Input => {
+            out.push("let value = match stdin.get(pos) {".to_string());
+            out.push("    Some(&value) => value,".to_string());
+            out.push("    None => {".to_string());
+            out.push(format!("        vm.set_ip({});", ins.addr));
+            out.push("        vm.set_rel_base(rb);".to_string());
+            out.push("        vm.set_steps(steps);".to_string());
+            out.push("        vm.waiting_for_input = true;".to_string());
+            out.push("        return stdout;".to_string());
+            out.push("    }".to_string());
+            out.push("};".to_string());
+            out.push("pos += 1;".to_string());
+            out.push("steps += 1;".to_string());
+            store(ins, "value", out);
+        }
+        Opcode::Output => {
+            out.push(format!("stdout.push({});", operand(ins, 0)));
+            out.push("steps += 1;".to_string());
+        }
+        Opcode::JumpIfTrue | Opcode::JumpIfFalse => {
+            out.push(format!("let cond: i64 = {};", operand(ins, 0)));
+            out.push(format!("let target: i64 = {};", operand(ins, 1)));
+            let test = if ins.opcode == Opcode::JumpIfTrue {
+                "!="
+            } else {
+                "=="
+            };
+            out.push(format!(
+                "ip = if cond {} 0 {{ target as usize }} else {{ {} }};",
+                test,
+                ins.next_addr()
+            ));
+            out.push("steps += 1;".to_string());
+        }
+        Opcode::RelativeBaseOffset => {
+            out.push(format!("rb += {};", operand(ins, 0)));
+            out.push("steps += 1;".to_string());
+        }
+        Opcode::Halt => {
+            out.push(format!("vm.set_ip({});", ins.addr));
+            out.push("vm.set_rel_base(rb);".to_string());
+            out.push("vm.set_steps(steps);".to_string());
+            out.push("vm.done = true;".to_string());
+            out.push("return stdout;".to_string());
+        }
+        Opcode::Invalid => unreachable!(),
+    }
+}
+
+// Splits the static blocks into the segments that get their own match arm.
+// Inputs start a segment so that a machine waiting for input resumes in
+// compiled code; invalid instructions are left to the interpreter to report.
+fn segments(cfg: &ControlFlowGraph) -> Vec<Vec<&Instruction>> {
+    let mut segments = Vec::new();
+    for block in cfg.blocks.values().filter(|block| !block.self_modified) {
+        let mut segment: Vec<&Instruction> = Vec::new();
+        for ins in block.instructions.iter() {
+            if ins.opcode == Opcode::Invalid {
+                break;
+            }
+            if ins.opcode == Opcode::Input && !segment.is_empty() {
+                segments.push(segment);
+                segment = Vec::new();
+            }
+            segment.push(ins);
+        }
+        if !segment.is_empty() {
+            segments.push(segment);
+        }
+    }
+    segments
+}
+
+fn array<T: ToString>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// Translates a program into a Rust module `name` whose `execute` behaves
+// like `IntcodeComputer::execute` on a machine loaded with the program.
+// Static blocks become straight-line code; everything else, including
+// computed jumps into unanalysed code, runs on the interpreter. Once
+// compiled code is overwritten the rest of the run is interpreted too.
+// The including module must have `IntcodeComputer` and `Step` in scope.
+pub fn compile(program: &[i64], name: &str) -> String {
+    // Computed jumps usually return to the instruction after a call, which
+    // the graph never reaches through an always-taken jump, so those
+    // addresses become extra entry points.
+    let mut entries = vec![0];
+    let cfg = loop {
+        let cfg = ControlFlowGraph::build_with_entries(program, &entries);
+        if !cfg.blocks.values().any(|block| block.dynamic_jump) {
+            break cfg;
+        }
+        let returns: Vec<usize> = cfg
+            .blocks
+            .values()
+            .filter_map(|block| {
+                let ins = block.instructions.last()?;
+                let next = ins.next_addr();
+                if ins.is_jump() && !cfg.graph.contains_edge(block.start, next) {
+                    Some(next)
+                } else {
+                    None
+                }
+            })
+            .filter(|addr| *addr < program.len() && !entries.contains(addr))
+            .collect();
+        if returns.is_empty() {
+            break cfg;
+        }
+        entries.extend(returns);
+    };
+    let segments = segments(&cfg);
+    let mut compiled = vec![false; program.len()];
+    for segment in segments.iter() {
+        for ins in segment.iter() {
+            for cell in compiled.iter_mut().take(ins.next_addr()).skip(ins.addr) {
+                *cell = true;
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    out.push("// Generated by intcode2rust. Do not edit.".to_string());
+    out.push("#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]".to_string());
+    out.push(format!("pub mod {} {{", name));
+    out.push("    use super::{IntcodeComputer, Step};".to_string());
+    out.push(String::new());
+    out.push(format!(
+        "    const CODE: [i64; {}] = [{}];",
+        program.len(),
+        array(program)
+    ));
+    out.push(format!(
+        "    const COMPILED: [bool; {}] = [{}];",
+        program.len(),
+        array(&compiled)
+    ));
+    out.push(
+        r#"
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {"#
+            .to_string(),
+    );
+    for segment in segments.iter() {
+        out.push(format!("                {} => {{", segment[0].addr));
+        for ins in segment.iter() {
+            let mut body = Vec::new();
+            translate(ins, &mut body);
+            out.push(format!("                    // {}", ins));
+            if body.len() == 1 || ins.opcode == Opcode::Halt {
+                out.extend(
+                    body.into_iter()
+                        .map(|l| format!("                    {}", l)),
+                );
+            } else {
+                out.push("                    {".to_string());
+                out.extend(
+                    body.into_iter()
+                        .map(|l| format!("                        {}", l)),
+                );
+                out.push("                    }".to_string());
+            }
+        }
+        let last = segment[segment.len() - 1];
+        if !last.is_jump() && last.opcode != Opcode::Halt {
+            out.push(format!("                    ip = {};", last.next_addr()));
+        }
+        out.push("                }".to_string());
+    }
+    out.push(
+        r#"                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}"#
+        .to_string(),
+    );
+
+    let mut source = out.join("\n");
+    source.push('\n');
+    source
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::differential::generate;
+    use crate::intcode::{IntcodeComputer, Step};
+    use crate::rng::Rng;
+    use std::{env, fs};
+
+    include!("../tests/fixtures/quine.rs");
+    include!("../tests/fixtures/compare.rs");
+    include!("../tests/fixtures/patched_jump.rs");
+    include!("../tests/fixtures/position_jump.rs");
+    include!("../tests/fixtures/feedback_loop.rs");
+    include!("../tests/fixtures/overwritten.rs");
+    include!("../tests/fixtures/generated.rs");
+
+    pub const QUINE: [i64; 16] = [
+        109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+    ];
+    pub const COMPARE: [i64; 47] = [
+        3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36, 98, 0, 0,
+        1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104, 999, 1105, 1, 46, 1101, 1000, 1, 20, 4, 20,
+        1105, 1, 46, 98, 99,
+    ];
+    pub const PATCHED_JUMP: [i64; 13] = [3, 3, 1105, -1, 9, 1101, 0, 0, 12, 4, 12, 99, 1];
+    pub const POSITION_JUMP: [i64; 16] = [3, 12, 6, 12, 15, 1, 13, 14, 13, 4, 13, 99, -1, 0, 1, 9];
+    pub const FEEDBACK_LOOP: [i64; 29] = [
+        3, 26, 1001, 26, -4, 26, 3, 27, 1002, 27, 2, 27, 1, 27, 26, 27, 4, 27, 1001, 28, -1, 28,
+        1005, 28, 6, 99, 0, 0, 5,
+    ];
+
+    pub const OVERWRITTEN: [i64; 9] = [109, 4, 21101, 0, 9, 3, 104, 7, 99];
+
+    type Compiled = fn(&mut IntcodeComputer, &[i64]) -> Vec<i64>;
+
+    // Seeds of the `generate` programs compiled into `GENERATED`.
+    const GENERATED_SEEDS: u64 = 32;
+
+    // One module per seed, then the `GENERATED` table of seeds and their
+    // compiled `execute`.
+    fn generated_fixture() -> String {
+        let mut source = String::new();
+        let mut table = String::new();
+        for seed in 0..GENERATED_SEEDS {
+            let name = format!("generated_{}", seed);
+            source.push_str(&compile(&generate(&mut Rng::new(seed)).program, &name));
+            source.push('\n');
+            table.push_str(&format!("    ({}, {}::execute),\n", seed, name));
+        }
+        source.push_str(&format!(
+            "pub const GENERATED: [(u64, Compiled); {}] = [\n{}];\n",
+            GENERATED_SEEDS, table
+        ));
+        source
+    }
+
+    // Runs both machines through the same sequence of `execute` calls.
+    fn assert_same(program: &[i64], compiled: Compiled, inputs: &[&[i64]]) {
+        let mut expected = IntcodeComputer::new();
+        let mut actual = IntcodeComputer::new();
+        expected.load_program(program);
+        actual.load_program(program);
+        for stdin in inputs {
+            assert_eq!(compiled(&mut actual, stdin), expected.execute(stdin));
+            assert_eq!(actual.memory, expected.memory);
+            assert_eq!(actual.ip(), expected.ip());
+            assert_eq!(actual.rel_base(), expected.rel_base());
+            assert_eq!(actual.steps(), expected.steps());
+            assert_eq!(actual.waiting_for_input, expected.waiting_for_input);
+            assert_eq!(actual.done, expected.done);
+        }
+    }
+    // Compares `source` with tests/fixtures/`name`.rs, or writes it there
+    // when run as `BLESS=1 cargo test`.
+    fn check_fixture(name: &str, source: &str) {
+        let path = format!("{}/tests/fixtures/{}.rs", env!("CARGO_MANIFEST_DIR"), name);
+        if env::var_os("BLESS").is_some() {
+            fs::write(&path, source).unwrap();
+            return;
+        }
+        let fixture = fs::read_to_string(&path).unwrap_or_default();
+        assert!(
+            fixture == source,
+            "{} is out of date, rerun with BLESS=1 to rewrite it",
+            path
+        );
+    }
+    #[test]
+    fn test_fixtures_up_to_date() {
+        check_fixture("quine", &compile(&QUINE, "quine"));
+        check_fixture("compare", &compile(&COMPARE, "compare"));
+        check_fixture("patched_jump", &compile(&PATCHED_JUMP, "patched_jump"));
+        check_fixture("position_jump", &compile(&POSITION_JUMP, "position_jump"));
+        check_fixture("feedback_loop", &compile(&FEEDBACK_LOOP, "feedback_loop"));
+        check_fixture("overwritten", &compile(&OVERWRITTEN, "overwritten"));
+        check_fixture("generated", &generated_fixture());
+    }
+    #[test]
+    fn test_quine() {
+        assert_same(&QUINE, quine::execute, &[&[]]);
+    }
+    #[test]
+    fn test_compare() {
+        for input in [4, 8, 14].iter() {
+            assert_same(&COMPARE, compare::execute, &[&[*input]]);
+        }
+        assert_same(&COMPARE, compare::execute, &[&[], &[8], &[]]);
+    }
+    #[test]
+    fn test_recording() {
+        // recording falls back to the interpreter, which logs every step
+        let mut computer = IntcodeComputer::new();
+        computer.load_program(&COMPARE);
+        computer.start_recording();
+        assert_eq!(compare::execute(&mut computer, &[8]), [1000]);
+        assert_eq!(computer.steps(), 6);
+        assert_eq!(
+            computer.stop_recording().unwrap().to_string(),
+            "0 in 8\n4 out 1000\n"
+        );
+    }
+    #[test]
+    fn test_self_modifying() {
+        assert_same(&PATCHED_JUMP, patched_jump::execute, &[&[4]]);
+        assert_same(&PATCHED_JUMP, patched_jump::execute, &[&[0]]);
+    }
+    #[test]
+    fn test_computed_jump() {
+        assert_same(&POSITION_JUMP, position_jump::execute, &[&[4]]);
+        assert_same(&POSITION_JUMP, position_jump::execute, &[&[0]]);
+    }
+    #[test]
+    fn test_feedback_loop() {
+        let mut signals: Vec<&[i64]> = vec![&[9, 0]];
+        signals.extend([&[1][..], &[7], &[100], &[-3], &[50]].iter());
+        assert_same(&FEEDBACK_LOOP, feedback_loop::execute, &signals);
+    }
+    #[test]
+    fn test_overwritten_code() {
+        // the relative store turns the compiled `out 7` into `out 9`
+        assert_same(&OVERWRITTEN, overwritten::execute, &[&[]]);
+    }
+}
@@ -1,64 +1,42 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-
-fn fuel_req(mass: u32) -> u32 {
-    let intermediate = (mass as f32 / 3.0).floor() as u32;
-    if intermediate <= 2 {
-        0
-    } else {
-        intermediate - 2
-    }
-}
-
-fn fuel_req_fuel(fuel: u32) -> u32 {
-    let fuel_req_by_fuel = fuel_req(fuel);
-    if fuel_req_by_fuel == 0 {
-        fuel
-    } else {
-        fuel + fuel_req_fuel(fuel_req_by_fuel)
-    }
-}
+use adventofcode2019::fuel::{parse_masses, FuelModel};
+use std::env;
+use std::fs;
+use std::process;
 
 fn main() {
     // Read modules mass from file
-    let file = File::open("input/01.txt").expect("unable to open modules file: input/01.txt");
-    let reader = BufReader::new(file);
+    let input =
+        fs::read_to_string("input/01.txt").expect("unable to open modules file: input/01.txt");
+    let masses = parse_masses(&input).unwrap_or_else(|e| {
+        eprintln!("input/01.txt: {}", e);
+        process::exit(1);
+    });
 
-    let mut fuel: u32 = 0;
-    let mut mfuel: u32 = 0;
-    for line in reader.lines() {
-        let line = line.unwrap();
-        let mass: u32 = line.parse().unwrap();
-        let fuel_for_mass = fuel_req(mass);
-        mfuel += fuel_for_mass;
-        let total_fuel = fuel_req_fuel(fuel_for_mass);
-        fuel += total_fuel;
-    }
-
-    println!("{}", mfuel);
-    println!("{}", fuel);
-}
-
-#[cfg(test)]
-pub mod tests {
-    use super::*;
-    #[test]
-    fn calculate_mass() {
-        assert_eq!(fuel_req(12), 2);
-        assert_eq!(fuel_req(14), 2);
-        assert_eq!(fuel_req(1969), 654);
-        assert_eq!(fuel_req(100756), 33583);
-    }
+    let model = FuelModel::default();
+    let modules = model.report(&masses).expect("fuel overflow");
+    let total = model
+        .with_fuel_for_fuel(true)
+        .report(&masses)
+        .expect("fuel overflow");
+    println!("{}", modules.total);
+    println!("{}", total.total);
 
-    #[test]
-    fn calculate_mass_with_fuel_req_fuel() {
-        let mass = 100756;
-        let fuel = fuel_req(mass);
-        let total_fuel = fuel_req_fuel(fuel);
-        assert_eq!(total_fuel, 50346);
-        let mass = 1969;
-        let fuel = fuel_req(mass);
-        let total_fuel = fuel_req_fuel(fuel);
-        assert_eq!(total_fuel, 966);
+    // `01 --breakdown` also lists every module and the ones needing 50% of the fuel
+    if env::args().any(|arg| arg == "--breakdown") {
+        for module in total.modules.iter() {
+            println!(
+                "{:>4} {:>8} {:>8}",
+                module.index + 1,
+                module.mass,
+                module.fuel
+            );
+        }
+        for module in total.dominant(50) {
+            println!(
+                "dominant: module {} ({} fuel)",
+                module.index + 1,
+                module.fuel
+            );
+        }
     }
 }
@@ -1,11 +1,7 @@
 use adventofcode2019::*;
 use std::fs;
 
-fn find_noun_verb(
-    computer: &mut IntcodeComputer,
-    program: &mut Vec<i64>,
-    output: i64,
-) -> (i64, i64) {
+fn find_noun_verb(computer: &mut IntcodeComputer, program: &mut [i64], output: i64) -> (i64, i64) {
     for i in 0..99 {
         for j in 0..99 {
             program[1] = i;
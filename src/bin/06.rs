@@ -1,49 +1,30 @@
-use petgraph::algo::astar;
-use petgraph::graphmap::DiGraphMap;
-use petgraph::Undirected;
-use std::collections::HashMap;
+use adventofcode2019::orbit::OrbitMap;
+use std::env;
 use std::fs::read_to_string;
-
-fn count_orbits(map: &DiGraphMap<&str, i32>, node: &str) -> u32 {
-    let mut count: u32 = 0;
-    for neighbor in map.neighbors(node) {
-        count += 1 + count_orbits(map, neighbor);
-    }
-    count
-}
+use std::process;
 
 fn main() {
     let input = read_to_string("input/06.txt").expect("Unable to read from file: input/06.txt");
-    let input = input.lines();
-    let mut map = DiGraphMap::new();
-
-    for line in input {
-        let orbit = line.split(')').collect::<Vec<_>>();
-        let object = orbit[0];
-        let orbiter = orbit[1];
-        map.add_edge(object, orbiter, 1);
-    }
-
-    let mut count: u32 = 0;
-    for node in map.nodes() {
-        count += count_orbits(&map, node);
-    }
+    let orbits = OrbitMap::parse(&input).unwrap_or_else(|e| {
+        eprintln!("input/06.txt: {}", e);
+        process::exit(1);
+    });
+    println!("{}", orbits.total_orbits());
 
-    println!("{}", count);
+    // Transfers between the objects YOU and SAN orbit
+    let transfers = orbits.orbital_transfers("YOU", "SAN").unwrap_or_else(|e| {
+        eprintln!("input/06.txt: {}", e);
+        process::exit(1);
+    });
+    println!("{}", transfers);
 
-    let graph = map.into_graph::<u32>().into_edge_type::<Undirected>();
-    let mut nodes = HashMap::new();
-    for node_ix in graph.node_indices() {
-        let node: &str = graph.node_weight(node_ix).unwrap();
-        nodes.insert(node, node_ix);
+    // `06 --dot` and `06 --tree` also print the map, the DOT one with the
+    // path from YOU to SAN highlighted
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--dot" => print!("{}", orbits.to_dot(Some(("YOU", "SAN"))).unwrap()),
+            "--tree" => print!("{}", orbits.to_tree()),
+            _ => {}
+        }
     }
-    let path = astar(
-        &graph,
-        nodes["YOU"],
-        |finish| finish == nodes["SAN"],
-        |e| *e.weight(),
-        |_| 0,
-    );
-
-    println!("{}", path.unwrap().0 - 2);
 }
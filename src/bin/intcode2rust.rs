@@ -0,0 +1,20 @@
+use adventofcode2019::aot::compile;
+use std::env;
+use std::fs;
+use std::process;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 3 {
+        eprintln!("usage: intcode2rust <program file> <module name>");
+        process::exit(1);
+    }
+    let input: String = fs::read_to_string(&args[1])
+        .unwrap_or_else(|_| panic!("Unable to read from file: {}", args[1]));
+    let program: Vec<i64> = input
+        .split(',')
+        .map(|x| x.trim().parse::<i64>().expect("unable to convert to int"))
+        .collect();
+
+    print!("{}", compile(&program, &args[2]));
+}
@@ -0,0 +1,380 @@
+use crate::intcode::{IntcodeComputer, Mode, Opcode};
+use petgraph::graphmap::DiGraphMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Instruction {
+    pub addr: usize,
+    pub opcode: Opcode,
+    pub modes: Vec<Mode>,
+    pub params: Vec<i64>,
+}
+
+impl Instruction {
+    // Anything the VM would panic on (unknown opcode or mode, immediate
+    // store, truncated parameters) decodes as `Opcode::Invalid`.
+    pub fn decode(program: &[i64], addr: usize) -> Self {
+        let invalid = Self {
+            addr,
+            opcode: Opcode::Invalid,
+            modes: Vec::new(),
+            params: Vec::new(),
+        };
+        let ins = match program.get(addr) {
+            Some(&ins) if ins >= 0 => ins,
+            _ => return invalid,
+        };
+        let (opcode, pmodes) = IntcodeComputer::decode(ins);
+        let len = opcode.param_len();
+        if addr + len >= program.len() {
+            return invalid;
+        }
+        let modes: Vec<Mode> = (0..len)
+            .map(|i| *pmodes.get(i).unwrap_or(&Mode::Positional))
+            .collect();
+        let stores_immediate = match opcode {
+            Opcode::Add | Opcode::Mul | Opcode::LessThan | Opcode::Equals => {
+                modes[2] == Mode::Immediate
+            }
+            Opcode::Input => modes[0] == Mode::Immediate,
+            _ => false,
+        };
+        if opcode == Opcode::Invalid || stores_immediate || modes.contains(&Mode::Invalid) {
+            return invalid;
+        }
+        Self {
+            addr,
+            opcode,
+            modes,
+            params: program[addr + 1..=addr + len].to_vec(),
+        }
+    }
+    pub fn next_addr(&self) -> usize {
+        self.addr + self.params.len() + 1
+    }
+    pub fn is_jump(&self) -> bool {
+        self.opcode == Opcode::JumpIfTrue || self.opcode == Opcode::JumpIfFalse
+    }
+    // Address written by this instruction, when it is known statically.
+    pub fn store_target(&self) -> Option<usize> {
+        match self.opcode {
+            Opcode::Add | Opcode::Mul | Opcode::LessThan | Opcode::Equals | Opcode::Input => {
+                let i = self.params.len() - 1;
+                if self.modes[i] == Mode::Positional && self.params[i] >= 0 {
+                    Some(self.params[i] as usize)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mnemonic = match self.opcode {
+            Opcode::Add => "add",
+            Opcode::Mul => "mul",
+            Opcode::Input => "in",
+            Opcode::Output => "out",
+            Opcode::JumpIfTrue => "jt",
+            Opcode::JumpIfFalse => "jf",
+            Opcode::LessThan => "lt",
+            Opcode::Equals => "eq",
+            Opcode::RelativeBaseOffset => "arb",
+            Opcode::Halt => "halt",
+            Opcode::Invalid => "invalid",
+        };
+        write!(f, "{}: {}", self.addr, mnemonic)?;
+        for (i, (mode, param)) in self.modes.iter().zip(&self.params).enumerate() {
+            write!(f, "{}", if i == 0 { " " } else { ", " })?;
+            match mode {
+                Mode::Positional => write!(f, "[{}]", param)?,
+                Mode::Relative => write!(f, "[rb{:+}]", param)?,
+                _ => write!(f, "{}", param)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Edge {
+    FallThrough,
+    Taken,
+    NotTaken,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BasicBlock {
+    pub start: usize,
+    pub instructions: Vec<Instruction>,
+    // The block ends in a jump whose target is only known at runtime.
+    pub dynamic_jump: bool,
+    // Some cell of the block is overwritten by a positional store.
+    pub self_modified: bool,
+}
+
+impl BasicBlock {
+    pub fn end(&self) -> usize {
+        let last = &self.instructions[self.instructions.len() - 1];
+        last.next_addr()
+    }
+}
+
+pub struct ControlFlowGraph {
+    pub blocks: BTreeMap<usize, BasicBlock>,
+    // Edges between the same pair of blocks share a weight: a jump to the
+    // next instruction is both `Taken` and `NotTaken`.
+    pub graph: DiGraphMap<usize, Vec<Edge>>,
+}
+
+// Static successors of an instruction; the flag is set for a jump that may
+// be taken to a target which can't be resolved.
+fn successors(ins: &Instruction, written: &BTreeSet<usize>) -> (Vec<(usize, Edge)>, bool) {
+    match ins.opcode {
+        Opcode::Halt | Opcode::Invalid => (Vec::new(), false),
+        Opcode::JumpIfTrue | Opcode::JumpIfFalse => {
+            let cond_known = ins.modes[0] == Mode::Immediate && !written.contains(&(ins.addr + 1));
+            let target_known =
+                ins.modes[1] == Mode::Immediate && !written.contains(&(ins.addr + 2));
+            let (may_take, may_skip) = if cond_known {
+                let taken = (ins.params[0] != 0) == (ins.opcode == Opcode::JumpIfTrue);
+                (taken, !taken)
+            } else {
+                (true, true)
+            };
+
+            let mut succ = Vec::new();
+            let mut dynamic = false;
+            if may_take {
+                if !target_known {
+                    dynamic = true;
+                } else if ins.params[1] >= 0 {
+                    // a negative target crashes the VM, so there is no edge
+                    succ.push((ins.params[1] as usize, Edge::Taken));
+                }
+            }
+            if may_skip {
+                succ.push((ins.next_addr(), Edge::NotTaken));
+            }
+            (succ, dynamic)
+        }
+        _ => (vec![(ins.next_addr(), Edge::FallThrough)], false),
+    }
+}
+
+impl ControlFlowGraph {
+    pub fn build(program: &[i64]) -> Self {
+        Self::build_from(program, 0)
+    }
+    // Builds the graph of blocks reachable from `entry` in the given memory
+    // image. Immediate conditions and targets are trusted unless a positional
+    // store in reachable code overwrites them. Programs that patch their own
+    // opcodes (like day 5) can be analysed from a snapshot taken after the
+    // patch, e.g. `program_memory()` and `ip()` of a stepped computer.
+    pub fn build_from(program: &[i64], entry: usize) -> Self {
+        Self::build_with_entries(program, &[entry])
+    }
+    pub fn build_with_entries(program: &[i64], entries: &[usize]) -> Self {
+        let mut written = BTreeSet::new();
+        let mut decoded = BTreeMap::new();
+        loop {
+            decoded.clear();
+            let mut worklist = entries.to_vec();
+            while let Some(addr) = worklist.pop() {
+                if decoded.contains_key(&addr) {
+                    continue;
+                }
+                let ins = Instruction::decode(program, addr);
+                let (succ, _) = successors(&ins, &written);
+                worklist.extend(succ.iter().map(|(next, _)| *next));
+                decoded.insert(addr, ins);
+            }
+            let stores = decoded
+                .values()
+                .filter_map(Instruction::store_target)
+                .collect::<BTreeSet<_>>();
+            if stores.is_subset(&written) {
+                break;
+            }
+            written.extend(stores);
+        }
+
+        let mut leaders: BTreeSet<usize> = entries.iter().copied().collect();
+        for ins in decoded.values().filter(|ins| ins.is_jump()) {
+            leaders.extend(successors(ins, &written).0.iter().map(|(next, _)| *next));
+        }
+
+        let mut blocks = BTreeMap::new();
+        let mut graph: DiGraphMap<usize, Vec<Edge>> = DiGraphMap::new();
+        for &leader in leaders.iter() {
+            graph.add_node(leader);
+            let mut block = BasicBlock {
+                start: leader,
+                instructions: Vec::new(),
+                dynamic_jump: false,
+                self_modified: false,
+            };
+            let mut addr = leader;
+            let edges = loop {
+                let ins = &decoded[&addr];
+                block.instructions.push(ins.clone());
+                block.self_modified |= written.range(addr..ins.next_addr()).next().is_some();
+                let (succ, dynamic) = successors(ins, &written);
+                block.dynamic_jump = dynamic;
+                if ins.is_jump() || succ.is_empty() || leaders.contains(&succ[0].0) {
+                    break succ;
+                }
+                addr = succ[0].0;
+            };
+            for (next, edge) in edges {
+                match graph.edge_weight_mut(leader, next) {
+                    Some(kinds) => kinds.push(edge),
+                    None => {
+                        graph.add_edge(leader, next, vec![edge]);
+                    }
+                }
+            }
+            blocks.insert(leader, block);
+        }
+
+        Self { blocks, graph }
+    }
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph cfg {\n    node [shape=box, fontname=monospace];\n");
+        for block in self.blocks.values() {
+            let label: String = block
+                .instructions
+                .iter()
+                .map(|ins| format!("{}\\l", ins))
+                .collect();
+            let style = if block.self_modified {
+                ", style=dashed"
+            } else {
+                ""
+            };
+            dot.push_str(&format!(
+                "    b{} [label=\"{}\"{}];\n",
+                block.start, label, style
+            ));
+        }
+        if self.blocks.values().any(|block| block.dynamic_jump) {
+            dot.push_str("    dynamic [shape=diamond, label=\"?\"];\n");
+        }
+        for (from, to, edges) in self.graph.all_edges() {
+            for edge in edges {
+                let label = match edge {
+                    Edge::FallThrough => "",
+                    Edge::Taken => "taken",
+                    Edge::NotTaken => "not taken",
+                };
+                dot.push_str(&format!(
+                    "    b{} -> b{} [label=\"{}\"];\n",
+                    from, to, label
+                ));
+            }
+        }
+        for block in self.blocks.values().filter(|block| block.dynamic_jump) {
+            dot.push_str(&format!(
+                "    b{} -> dynamic [label=\"taken\", style=dashed];\n",
+                block.start
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    #[test]
+    fn test_straight_line() {
+        let cfg = ControlFlowGraph::build(&[1101, 100, -1, 7, 4, 7, 99, 0]);
+        assert_eq!(cfg.blocks.len(), 1);
+        let block = &cfg.blocks[&0];
+        assert_eq!(block.instructions.len(), 3);
+        assert_eq!(block.end(), 7);
+        assert!(!block.dynamic_jump);
+        assert!(!block.self_modified);
+        assert_eq!(block.instructions[0].to_string(), "0: add 100, -1, [7]");
+    }
+    #[test]
+    fn test_branches() {
+        let cfg = ControlFlowGraph::build(&[3, 3, 1105, -1, 9, 1101, 0, 0, 12, 4, 12, 99, 1]);
+        assert_eq!(
+            cfg.blocks.keys().copied().collect::<Vec<_>>(),
+            vec![0, 5, 9]
+        );
+        // the input overwrites the jump condition, so both edges remain
+        assert!(cfg.blocks[&0].self_modified);
+        assert_eq!(cfg.graph.edge_weight(0, 9), Some(&vec![Edge::Taken]));
+        assert_eq!(cfg.graph.edge_weight(0, 5), Some(&vec![Edge::NotTaken]));
+        assert_eq!(cfg.graph.edge_weight(5, 9), Some(&vec![Edge::FallThrough]));
+        assert_eq!(cfg.graph.neighbors(9).count(), 0);
+    }
+    #[test]
+    fn test_constant_condition() {
+        let cfg = ControlFlowGraph::build(&[1105, 1, 4, 99, 104, 7, 99]);
+        assert_eq!(cfg.blocks.keys().copied().collect::<Vec<_>>(), vec![0, 4]);
+        assert_eq!(cfg.graph.edge_weight(0, 4), Some(&vec![Edge::Taken]));
+    }
+    #[test]
+    fn test_jump_to_next() {
+        let cfg = ControlFlowGraph::build(&[3, 0, 1005, 0, 5, 104, 7, 99]);
+        assert_eq!(cfg.blocks.keys().copied().collect::<Vec<_>>(), vec![0, 5]);
+        assert_eq!(
+            cfg.graph.edge_weight(0, 5),
+            Some(&vec![Edge::Taken, Edge::NotTaken])
+        );
+        let dot = cfg.to_dot();
+        assert!(dot.contains("b0 -> b5 [label=\"taken\"];"));
+        assert!(dot.contains("b0 -> b5 [label=\"not taken\"];"));
+    }
+    #[test]
+    fn test_patched_entry() {
+        let program = [3, 13, 1, 13, 6, 6, 1100, 1, 10, 99, 104, 42, 99, 0];
+        let cfg = ControlFlowGraph::build(&program);
+        assert_eq!(cfg.blocks[&0].instructions[2].opcode, Opcode::Invalid);
+
+        let mut computer = IntcodeComputer::new();
+        computer.load_program(&program);
+        computer.step(Some(5));
+        computer.step(None);
+        let cfg = ControlFlowGraph::build_from(&computer.program_memory(), computer.ip());
+        assert_eq!(cfg.blocks.keys().copied().collect::<Vec<_>>(), vec![6, 10]);
+        assert_eq!(cfg.blocks[&6].instructions[0].to_string(), "6: jt 1, 10");
+        assert_eq!(cfg.graph.edge_weight(6, 10), Some(&vec![Edge::Taken]));
+    }
+    #[test]
+    fn test_dynamic_jump() {
+        let cfg =
+            ControlFlowGraph::build(&[3, 12, 6, 12, 15, 1, 13, 14, 13, 4, 13, 99, -1, 0, 1, 9]);
+        assert!(cfg.blocks[&0].dynamic_jump);
+        assert_eq!(cfg.graph.edge_weight(0, 5), Some(&vec![Edge::NotTaken]));
+        let dot = cfg.to_dot();
+        assert!(dot.starts_with("digraph cfg {"));
+        assert!(dot.contains("b0 -> dynamic"));
+        assert!(dot.contains("b0 -> b5 [label=\"not taken\"];"));
+        assert!(dot.contains("0: in [12]\\l2: jf [12], [15]\\l"));
+    }
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let program = [3, 12, 6, 12, 15, 1, 13, 14, 13, 4, 13, 99, -1, 0, 1, 9];
+        let cfg = ControlFlowGraph::build(&program);
+        let blocks: Vec<&BasicBlock> = cfg.blocks.values().collect();
+        let json = serde_json::to_string(&blocks).unwrap();
+        let decoded: Vec<BasicBlock> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.iter().collect::<Vec<_>>(), blocks);
+        let bytes = bincode::serialize(&blocks[0].instructions).unwrap();
+        let decoded: Vec<Instruction> = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(decoded, blocks[0].instructions);
+    }
+}
@@ -0,0 +1,465 @@
+use crate::intcode::{IntcodeComputer, MemoryDump, Step};
+use crate::rng::Rng;
+use std::panic::{self, AssertUnwindSafe};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Case {
+    pub program: Vec<i64>,
+    // Each batch is passed to one `execute` call.
+    pub batches: Vec<Vec<i64>>,
+}
+
+const DATA_CELLS: usize = 8;
+
+#[derive(Clone, Copy)]
+enum Param {
+    Imm(i64),
+    Pos(i64),
+    Rel(i64),
+    PosCounter(usize),
+    PosData(usize),
+    RelData(usize),
+    Target(usize),
+}
+
+struct Ins {
+    opcode: i64,
+    params: Vec<Param>,
+}
+
+enum Item {
+    Single(Ins),
+    Loop(Vec<Ins>),
+}
+
+fn read_param(rng: &mut Rng, counters: usize) -> Param {
+    match rng.below(10) {
+        0..=2 => Param::Imm(rng.range(-20, 20)),
+        3..=5 => Param::PosData(rng.below(DATA_CELLS)),
+        6 if counters > 0 => Param::PosCounter(rng.below(counters)),
+        6 | 7 => Param::Pos(rng.range(0, 64)),
+        _ => Param::Rel(rng.range(-3, 64)),
+    }
+}
+
+fn store_param(rng: &mut Rng) -> Param {
+    if rng.below(2) == 0 {
+        Param::PosData(rng.below(DATA_CELLS))
+    } else {
+        Param::RelData(rng.below(DATA_CELLS))
+    }
+}
+
+// A straight-line or forward-jumping instruction; jump targets are filled in
+// once the enclosing scope is known.
+fn instruction(rng: &mut Rng, counters: usize) -> Ins {
+    let opcode = [1, 2, 3, 4, 5, 6, 7, 8, 9][rng.below(9)];
+    let params = match opcode {
+        1 | 2 | 7 | 8 => vec![
+            read_param(rng, counters),
+            read_param(rng, counters),
+            store_param(rng),
+        ],
+        3 => vec![store_param(rng)],
+        4 => vec![read_param(rng, counters)],
+        5 | 6 => vec![read_param(rng, counters), Param::Target(0)],
+        // the relative base only grows, so relative stores stay in the data
+        _ => vec![Param::Imm(rng.range(0, 5))],
+    };
+    Ins { opcode, params }
+}
+
+fn pick_targets(rng: &mut Rng, body: &mut [Ins], first: usize, end: usize) {
+    for (i, ins) in body.iter_mut().enumerate() {
+        if let Some(Param::Target(target)) = ins.params.last_mut() {
+            *target = first + i + 1 + rng.below(end - first - i);
+        }
+    }
+}
+
+// Generates a random program that halts or waits for input in a bounded
+// number of steps: jumps only go forward, except the back edge of counted
+// loops whose counters are never stored to.
+pub fn generate(rng: &mut Rng) -> Case {
+    let mut items = Vec::new();
+    let mut counters = 0;
+    for _ in 0..1 + rng.below(24) {
+        if rng.below(7) == 0 {
+            counters += 1;
+            let body = (0..1 + rng.below(6))
+                .map(|_| instruction(rng, counters))
+                .collect();
+            items.push(Item::Loop(body));
+        } else {
+            items.push(Item::Single(instruction(rng, counters)));
+        }
+    }
+
+    // flatten, resolving jump targets to instruction indices
+    let mut code: Vec<Ins> = Vec::new();
+    let mut starts = Vec::new();
+    let mut singles = Vec::new();
+    let mut counter = 0;
+    for item in items {
+        starts.push(code.len());
+        match item {
+            Item::Single(ins) => {
+                singles.push(starts.len() - 1);
+                code.push(ins);
+            }
+            Item::Loop(mut body) => {
+                let first = code.len();
+                let end = first + body.len();
+                pick_targets(rng, &mut body, first, end);
+                code.extend(body);
+                code.push(Ins {
+                    opcode: 1001,
+                    params: vec![
+                        Param::PosCounter(counter),
+                        Param::Imm(-1),
+                        Param::PosCounter(counter),
+                    ],
+                });
+                code.push(Ins {
+                    opcode: 1005,
+                    params: vec![Param::PosCounter(counter), Param::Target(first)],
+                });
+                counter += 1;
+            }
+        }
+    }
+    starts.push(code.len());
+    code.push(Ins {
+        opcode: 99,
+        params: Vec::new(),
+    });
+    for i in singles {
+        if let Some(Param::Target(target)) = code[starts[i]].params.last_mut() {
+            *target = starts[i + 1 + rng.below(starts.len() - i - 1)];
+        }
+    }
+
+    // assemble: code, then loop counters, then data
+    let mut addrs = Vec::new();
+    let mut len = 0;
+    for ins in code.iter() {
+        addrs.push(len);
+        len += ins.params.len() + 1;
+    }
+    let counter_base = len as i64;
+    let data_base = counter_base + counters as i64;
+    let mut program = Vec::new();
+    for ins in code.iter() {
+        let mut value = ins.opcode;
+        let mut params = Vec::new();
+        for (i, param) in ins.params.iter().enumerate() {
+            let (mode, param) = match *param {
+                Param::Imm(value) => (1, value),
+                Param::Pos(addr) => (0, addr),
+                Param::Rel(offset) => (2, offset),
+                Param::PosCounter(i) => (0, counter_base + i as i64),
+                Param::PosData(i) => (0, data_base + i as i64),
+                Param::RelData(i) => (2, data_base + i as i64),
+                Param::Target(i) => (1, addrs[i] as i64),
+            };
+            if ins.opcode < 10 {
+                value += mode * 10i64.pow(i as u32 + 2);
+            }
+            params.push(param);
+        }
+        program.push(value);
+        program.extend(params);
+    }
+    for _ in 0..counters {
+        program.push(rng.range(1, 5));
+    }
+    for _ in 0..DATA_CELLS {
+        program.push(rng.range(-10, 10));
+    }
+
+    let batches = (0..1 + rng.below(3))
+        .map(|_| (0..rng.below(4)).map(|_| rng.range(-20, 20)).collect())
+        .collect();
+    Case { program, batches }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    pub memory: MemoryDump,
+    pub ip: usize,
+    pub rel_base: i64,
+    pub steps: usize,
+    pub waiting_for_input: bool,
+    pub done: bool,
+}
+
+impl From<&IntcodeComputer> for State {
+    fn from(computer: &IntcodeComputer) -> Self {
+        Self {
+            memory: computer.memory_dump(),
+            ip: computer.ip(),
+            rel_base: computer.rel_base(),
+            steps: computer.steps(),
+            waiting_for_input: computer.waiting_for_input,
+            done: computer.done,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Outcome {
+    Finished {
+        outputs: Vec<Vec<i64>>,
+        state: State,
+    },
+    Panicked,
+}
+
+pub trait Backend {
+    fn name(&self) -> &str;
+    fn load_program(&mut self, program: &[i64]);
+    fn execute(&mut self, stdin: &[i64]) -> Vec<i64>;
+    fn state(&self) -> State;
+}
+
+impl Backend for IntcodeComputer {
+    fn name(&self) -> &str {
+        "interpreter"
+    }
+    fn load_program(&mut self, program: &[i64]) {
+        IntcodeComputer::load_program(self, program);
+    }
+    fn execute(&mut self, stdin: &[i64]) -> Vec<i64> {
+        IntcodeComputer::execute(self, stdin)
+    }
+    fn state(&self) -> State {
+        State::from(self)
+    }
+}
+
+// Drives the machine one `step` at a time instead of through `execute`.
+#[derive(Default)]
+pub struct Stepwise(pub IntcodeComputer);
+
+impl Backend for Stepwise {
+    fn name(&self) -> &str {
+        "stepwise"
+    }
+    fn load_program(&mut self, program: &[i64]) {
+        self.0.load_program(program);
+    }
+    fn execute(&mut self, stdin: &[i64]) -> Vec<i64> {
+        let computer = &mut self.0;
+        let mut stdin = stdin.iter().copied().peekable();
+        let mut stdout = Vec::new();
+        computer.waiting_for_input = computer.waiting_for_input && stdin.peek().is_none();
+        loop {
+            match computer.step(stdin.peek().copied()) {
+                Step::Continue => (),
+                Step::Input => {
+                    stdin.next();
+                }
+                Step::Output(value) => stdout.push(value),
+                Step::WaitingForInput => {
+                    computer.waiting_for_input = true;
+                    return stdout;
+                }
+                Step::Halted => return stdout,
+            }
+        }
+    }
+    fn state(&self) -> State {
+        State::from(&self.0)
+    }
+}
+
+// Executes every batch with the journal on, rewinds it and executes it again,
+// so the rewind has to restore the exact state the batch started from.
+pub struct Journaled(pub IntcodeComputer);
+
+impl Default for Journaled {
+    fn default() -> Self {
+        let mut computer = IntcodeComputer::new();
+        computer.enable_journal();
+        Self(computer)
+    }
+}
+
+impl Backend for Journaled {
+    fn name(&self) -> &str {
+        "journaled"
+    }
+    fn load_program(&mut self, program: &[i64]) {
+        self.0.load_program(program);
+    }
+    fn execute(&mut self, stdin: &[i64]) -> Vec<i64> {
+        let start = self.0.journal().map_or(0, <[_]>::len);
+        let first = self.0.execute(stdin);
+        let consumed = self.0.rewind_to(start);
+        assert_eq!(consumed.as_slice(), &stdin[..consumed.len()]);
+        let second = self.0.execute(stdin);
+        assert_eq!(first, second, "replay after rewind diverged");
+        second
+    }
+    fn state(&self) -> State {
+        State::from(&self.0)
+    }
+}
+
+pub fn run(backend: &mut dyn Backend, case: &Case) -> Outcome {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        backend.load_program(&case.program);
+        let outputs = case
+            .batches
+            .iter()
+            .map(|stdin| backend.execute(stdin))
+            .collect();
+        (outputs, backend.state())
+    }));
+    match result {
+        Ok((outputs, state)) => Outcome::Finished { outputs, state },
+        Err(_) => Outcome::Panicked,
+    }
+}
+
+// Compares every backend against the interpreter on one case. `Stepwise`
+// and `Journaled` share its `step`, so they only cover the paths around it;
+// compiled code is the one independent implementation.
+pub fn check(case: &Case, backends: &mut [&mut dyn Backend]) -> Result<(), String> {
+    let expected = run(&mut IntcodeComputer::new(), case);
+    for backend in backends.iter_mut() {
+        let actual = run(*backend, case);
+        if actual != expected {
+            return Err(format!(
+                "{} diverged from the interpreter on {:?}:\nexpected {:?}\nactual {:?}",
+                backend.name(),
+                case,
+                expected,
+                actual
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::aot::tests::{compare, feedback_loop, position_jump, quine};
+
+    type Execute = fn(&mut IntcodeComputer, &[i64]) -> Vec<i64>;
+
+    struct Compiled {
+        computer: IntcodeComputer,
+        execute: Execute,
+    }
+
+    impl Backend for Compiled {
+        fn name(&self) -> &str {
+            "compiled"
+        }
+        fn load_program(&mut self, program: &[i64]) {
+            self.computer.load_program(program);
+        }
+        fn execute(&mut self, stdin: &[i64]) -> Vec<i64> {
+            (self.execute)(&mut self.computer, stdin)
+        }
+        fn state(&self) -> State {
+            State::from(&self.computer)
+        }
+    }
+
+    #[test]
+    fn test_generated_programs() {
+        let mut finished = 0;
+        for seed in 0..2000 {
+            let case = generate(&mut Rng::new(seed));
+            let mut stepwise = Stepwise::default();
+            let mut journaled = Journaled::default();
+            if let Err(mismatch) = check(&case, &mut [&mut stepwise, &mut journaled]) {
+                panic!("seed {}: {}", seed, mismatch);
+            }
+            if let Outcome::Finished { .. } = run(&mut IntcodeComputer::new(), &case) {
+                finished += 1;
+            }
+        }
+        // overflowing arithmetic panics, but it should stay the exception
+        assert!(finished > 1800, "only {} cases finished", finished);
+    }
+    #[test]
+    fn test_generator_is_deterministic() {
+        assert_eq!(generate(&mut Rng::new(7)), generate(&mut Rng::new(7)));
+        assert_eq!(
+            generate(&mut Rng::from_bytes(b"intcode")),
+            generate(&mut Rng::from_bytes(b"intcode"))
+        );
+        assert_ne!(generate(&mut Rng::new(7)), generate(&mut Rng::new(8)));
+    }
+    #[test]
+    fn test_compiled_programs() {
+        let programs: [(&[i64], Execute); 4] = [
+            (&crate::aot::tests::QUINE, quine::execute),
+            (&crate::aot::tests::COMPARE, compare::execute),
+            (&crate::aot::tests::POSITION_JUMP, position_jump::execute),
+            (&crate::aot::tests::FEEDBACK_LOOP, feedback_loop::execute),
+        ];
+        let mut rng = Rng::new(2019);
+        for (program, execute) in programs.iter() {
+            for _ in 0..200 {
+                let batches = (0..1 + rng.below(6))
+                    .map(|_| (0..rng.below(3)).map(|_| rng.range(-20, 20)).collect())
+                    .collect();
+                let case = Case {
+                    program: program.to_vec(),
+                    batches,
+                };
+                let mut compiled = Compiled {
+                    computer: IntcodeComputer::new(),
+                    execute: *execute,
+                };
+                check(&case, &mut [&mut compiled]).unwrap();
+            }
+        }
+    }
+    #[test]
+    fn test_compiled_generated_programs() {
+        let mut rng = Rng::new(2020);
+        for (seed, execute) in crate::aot::tests::GENERATED.iter() {
+            let mut case = generate(&mut Rng::new(*seed));
+            for run in 0..20 {
+                // the generated batches first, then other inputs
+                if run > 0 {
+                    case.batches = (0..1 + rng.below(4))
+                        .map(|_| (0..rng.below(4)).map(|_| rng.range(-20, 20)).collect())
+                        .collect();
+                }
+                let mut compiled = Compiled {
+                    computer: IntcodeComputer::new(),
+                    execute: *execute,
+                };
+                if let Err(mismatch) = check(&case, &mut [&mut compiled]) {
+                    panic!("seed {}: {}", seed, mismatch);
+                }
+            }
+        }
+    }
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let case = generate(&mut Rng::new(2019));
+        let outcome = run(&mut IntcodeComputer::new(), &case);
+        let json = serde_json::to_string(&(&case, &outcome)).unwrap();
+        assert_eq!(
+            serde_json::from_str::<(Case, Outcome)>(&json).unwrap(),
+            (case.clone(), outcome.clone())
+        );
+        let bytes = bincode::serialize(&(&case, &outcome)).unwrap();
+        assert_eq!(
+            bincode::deserialize::<(Case, Outcome)>(&bytes).unwrap(),
+            (case, outcome)
+        );
+    }
+}
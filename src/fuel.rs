@@ -0,0 +1,292 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    Blank,
+    Negative,
+    Invalid,
+}
+
+// A module mass that could not be read, with its 1-based line number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub text: String,
+    pub kind: ParseErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::Blank => write!(f, "line {}: blank line", self.line),
+            ParseErrorKind::Negative => {
+                write!(f, "line {}: negative mass `{}`", self.line, self.text)
+            }
+            ParseErrorKind::Invalid => {
+                write!(f, "line {}: invalid mass `{}`", self.line, self.text)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// One module mass per line.
+pub fn parse_masses(input: &str) -> Result<Vec<u64>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let text = line.trim();
+            let error = |kind| ParseError {
+                line: i + 1,
+                text: text.to_string(),
+                kind,
+            };
+            if text.is_empty() {
+                return Err(error(ParseErrorKind::Blank));
+            }
+            text.parse::<u64>().map_err(|_| match text.parse::<i128>() {
+                Ok(n) if n < 0 => error(ParseErrorKind::Negative),
+                _ => error(ParseErrorKind::Invalid),
+            })
+        })
+        .collect()
+}
+
+// The day 1 models never fail: each round of fuel is at most a third of the
+// previous one, so even the fuel for fuel stays below mass / 2.
+pub fn fuel_req(mass: u64) -> u64 {
+    FuelModel::default().fuel(mass).unwrap()
+}
+
+// Fuel for the mass plus the fuel needed to carry that fuel, and so on.
+pub fn total_fuel_req(mass: u64) -> u64 {
+    FuelModel::default()
+        .with_fuel_for_fuel(true)
+        .fuel(mass)
+        .unwrap()
+}
+
+// Sums `fuel` over all modules, or None if the total does not fit in a u64.
+pub fn sum_fuel<F: Fn(u64) -> u64>(masses: &[u64], fuel: F) -> Option<u64> {
+    masses
+        .iter()
+        .try_fold(0u64, |total, &mass| total.checked_add(fuel(mass)))
+}
+
+// Rocket equation `mass / divisor - subtraction`, optionally applied again to
+// the fuel itself until it needs no more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FuelModel {
+    divisor: u64,
+    subtraction: u64,
+    fuel_for_fuel: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModuleFuel {
+    pub index: usize,
+    pub mass: u64,
+    pub fuel: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuelReport {
+    pub modules: Vec<ModuleFuel>,
+    pub total: u64,
+}
+
+impl Default for FuelModel {
+    fn default() -> Self {
+        Self {
+            divisor: 3,
+            subtraction: 2,
+            fuel_for_fuel: false,
+        }
+    }
+}
+
+impl FuelModel {
+    // None for a zero divisor.
+    pub fn new(divisor: u64, subtraction: u64) -> Option<Self> {
+        if divisor == 0 {
+            return None;
+        }
+        Some(Self {
+            divisor,
+            subtraction,
+            ..Self::default()
+        })
+    }
+    pub fn with_fuel_for_fuel(mut self, enabled: bool) -> Self {
+        self.fuel_for_fuel = enabled;
+        self
+    }
+    pub fn divisor(&self) -> u64 {
+        self.divisor
+    }
+    pub fn subtraction(&self) -> u64 {
+        self.subtraction
+    }
+    pub fn fuel_for_fuel(&self) -> bool {
+        self.fuel_for_fuel
+    }
+    // None if the fuel does not fit in a u64, or never stops growing because
+    // every round needs as much fuel as the previous one.
+    pub fn fuel(&self, mass: u64) -> Option<u64> {
+        let step = |mass: u64| (mass / self.divisor).saturating_sub(self.subtraction);
+        let mut fuel = step(mass);
+        if !self.fuel_for_fuel {
+            return Some(fuel);
+        }
+        let mut total: u64 = 0;
+        while fuel > 0 {
+            total = total.checked_add(fuel)?;
+            let next = step(fuel);
+            if next >= fuel {
+                return None;
+            }
+            fuel = next;
+        }
+        Some(total)
+    }
+    pub fn report(&self, masses: &[u64]) -> Option<FuelReport> {
+        let mut total: u64 = 0;
+        let mut modules = Vec::with_capacity(masses.len());
+        for (index, &mass) in masses.iter().enumerate() {
+            let fuel = self.fuel(mass)?;
+            total = total.checked_add(fuel)?;
+            modules.push(ModuleFuel { index, mass, fuel });
+        }
+        Some(FuelReport { modules, total })
+    }
+}
+
+impl FuelReport {
+    // The fewest modules that together need at least `percent` of the total
+    // fuel, heaviest consumer first.
+    pub fn dominant(&self, percent: u64) -> Vec<ModuleFuel> {
+        let mut modules = self.modules.clone();
+        modules.sort_by(|a, b| b.fuel.cmp(&a.fuel).then(a.index.cmp(&b.index)));
+        let target = u128::from(self.total) * u128::from(percent.min(100));
+        let mut covered: u128 = 0;
+        let count = modules
+            .iter()
+            .take_while(|module| {
+                let more = covered * 100 < target;
+                covered += u128::from(module.fuel);
+                more
+            })
+            .count();
+        modules.truncate(count);
+        modules
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    #[test]
+    fn test_fuel_req() {
+        assert_eq!(fuel_req(12), 2);
+        assert_eq!(fuel_req(14), 2);
+        assert_eq!(fuel_req(1969), 654);
+        assert_eq!(fuel_req(100756), 33583);
+        assert_eq!(fuel_req(2), 0);
+        // f32 rounds this mass up to 300_000_000.
+        assert_eq!(fuel_req(299_999_999), 99_999_997);
+    }
+    #[test]
+    fn test_total_fuel_req() {
+        assert_eq!(total_fuel_req(14), 2);
+        assert_eq!(total_fuel_req(1969), 966);
+        assert_eq!(total_fuel_req(100756), 50346);
+        assert_eq!(total_fuel_req(0), 0);
+        assert!(total_fuel_req(u64::MAX) < u64::MAX / 2);
+    }
+    #[test]
+    fn test_sum_fuel() {
+        let masses = [12, 14, 1969, 100756];
+        assert_eq!(sum_fuel(&masses, fuel_req), Some(2 + 2 + 654 + 33583));
+        assert_eq!(sum_fuel(&masses, total_fuel_req), Some(2 + 2 + 966 + 50346));
+        assert_eq!(sum_fuel(&[u64::MAX; 4], fuel_req), None);
+    }
+    #[test]
+    fn test_parse_masses() {
+        assert_eq!(parse_masses("12\n 14 \n1969\n"), Ok(vec![12, 14, 1969]));
+        assert_eq!(
+            parse_masses("12\n\n14").unwrap_err().to_string(),
+            "line 2: blank line"
+        );
+        assert_eq!(
+            parse_masses("12\n14\n-3").unwrap_err(),
+            ParseError {
+                line: 3,
+                text: "-3".to_string(),
+                kind: ParseErrorKind::Negative
+            }
+        );
+        assert_eq!(
+            parse_masses("1.5").unwrap_err().to_string(),
+            "line 1: invalid mass `1.5`"
+        );
+    }
+    #[test]
+    fn test_fuel_model() {
+        let day1 = FuelModel::default();
+        let recursive = day1.with_fuel_for_fuel(true);
+        for &mass in [12, 14, 1969, 100756, 299_999_999, u64::MAX].iter() {
+            assert_eq!(day1.fuel(mass), Some(fuel_req(mass)));
+            assert_eq!(recursive.fuel(mass), Some(total_fuel_req(mass)));
+        }
+        assert_eq!(FuelModel::new(3, 2), Some(day1));
+        assert_eq!(FuelModel::new(0, 2), None);
+        assert_eq!(FuelModel::new(2, 0).unwrap().fuel(100), Some(50));
+        assert_eq!(
+            FuelModel::new(2, 0)
+                .unwrap()
+                .with_fuel_for_fuel(true)
+                .fuel(100),
+            Some(97)
+        );
+        assert_eq!(FuelModel::new(1, 0).unwrap().fuel(100), Some(100));
+        assert_eq!(
+            FuelModel::new(1, 0)
+                .unwrap()
+                .with_fuel_for_fuel(true)
+                .fuel(100),
+            None
+        );
+        assert_eq!(
+            FuelModel::new(1, 1)
+                .unwrap()
+                .with_fuel_for_fuel(true)
+                .fuel(5),
+            Some(4 + 3 + 2 + 1)
+        );
+    }
+    #[test]
+    fn test_fuel_report() {
+        let masses = [12, 100756, 14, 1969, 100756];
+        let report = FuelModel::default()
+            .with_fuel_for_fuel(true)
+            .report(&masses)
+            .unwrap();
+        assert_eq!(report.total, 2 + 50346 + 2 + 966 + 50346);
+        assert_eq!(
+            report.modules[3],
+            ModuleFuel {
+                index: 3,
+                mass: 1969,
+                fuel: 966
+            }
+        );
+        let dominant: Vec<usize> = report.dominant(90).iter().map(|m| m.index).collect();
+        assert_eq!(dominant, [1, 4]);
+        let dominant: Vec<usize> = report.dominant(100).iter().map(|m| m.index).collect();
+        assert_eq!(dominant, [1, 4, 3, 0, 2]);
+        assert!(report.dominant(0).is_empty());
+        assert_eq!(FuelModel::new(1, 0).unwrap().report(&[u64::MAX, 1]), None);
+    }
+}
@@ -1,7 +1,12 @@
+use crate::recording::{Io, IoEvent, Recording};
 use std::collections::HashMap;
 use std::convert::From;
+use std::fmt;
+use std::future::Future;
 
-enum Opcode {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Opcode {
     Add,
     Mul,
     Input,
@@ -16,7 +21,7 @@ enum Opcode {
 }
 
 impl Opcode {
-    fn param_len(&self) -> usize {
+    pub fn param_len(&self) -> usize {
         match self {
             Self::Add => 3,
             Self::Mul => 3,
@@ -51,7 +56,9 @@ impl From<i64> for Opcode {
     }
 }
 
-enum Mode {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Mode {
     Positional,
     Immediate,
     Relative,
@@ -69,11 +76,146 @@ impl From<usize> for Mode {
     }
 }
 
-#[derive(Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Step {
+    Continue,
+    Input,
+    Output(i64),
+    WaitingForInput,
+    Halted,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemoryWrite {
+    pub addr: usize,
+    // None if the cell did not exist before the write
+    pub old: Option<i64>,
+    pub new: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JournalEntry {
+    // Instructions executed before this one, as `steps` counted them.
+    pub step: usize,
+    pub ip: usize,
+    pub next_ip: usize,
+    pub rel_base: i64,
+    pub next_rel_base: i64,
+    pub writes: Vec<MemoryWrite>,
+    pub input: Option<i64>,
+    pub output: Option<i64>,
+}
+
+// Sparse listing of memory cells, sorted by address.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemoryDump(pub Vec<(usize, i64)>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CellChange {
+    pub addr: usize,
+    // None where the cell is absent from that dump
+    pub before: Option<i64>,
+    pub after: Option<i64>,
+}
+
+impl MemoryDump {
+    pub fn from_program(program: &[i64]) -> Self {
+        Self(program.iter().copied().enumerate().collect())
+    }
+    pub fn get(&self, addr: usize) -> Option<i64> {
+        self.0
+            .binary_search_by_key(&addr, |(a, _)| *a)
+            .ok()
+            .map(|i| self.0[i].1)
+    }
+    // Cells that differ between `self` (before) and `other` (after).
+    pub fn diff(&self, other: &MemoryDump) -> Vec<CellChange> {
+        let mut changes = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.0.len() || j < other.0.len() {
+            let left = self.0.get(i).copied();
+            let right = other.0.get(j).copied();
+            let change = match (left, right) {
+                (Some((a, x)), Some((b, y))) if a == b => {
+                    i += 1;
+                    j += 1;
+                    if x == y {
+                        continue;
+                    }
+                    CellChange {
+                        addr: a,
+                        before: Some(x),
+                        after: Some(y),
+                    }
+                }
+                (Some((a, x)), Some((b, _))) if a < b => {
+                    i += 1;
+                    CellChange {
+                        addr: a,
+                        before: Some(x),
+                        after: None,
+                    }
+                }
+                (Some((a, x)), None) => {
+                    i += 1;
+                    CellChange {
+                        addr: a,
+                        before: Some(x),
+                        after: None,
+                    }
+                }
+                (_, Some((b, y))) => {
+                    j += 1;
+                    CellChange {
+                        addr: b,
+                        before: None,
+                        after: Some(y),
+                    }
+                }
+                (None, None) => unreachable!(),
+            };
+            changes.push(change);
+        }
+        changes
+    }
+}
+
+impl fmt::Display for MemoryDump {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (addr, value) in self.0.iter() {
+            writeln!(f, "{:>8}: {}", addr, value)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for CellChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.before, self.after) {
+            (Some(before), Some(after)) => write!(f, "~{:>8}: {} -> {}", self.addr, before, after),
+            (None, Some(after)) => write!(f, "+{:>8}: {}", self.addr, after),
+            (Some(before), None) => write!(f, "-{:>8}: {}", self.addr, before),
+            (None, None) => write!(f, " {:>8}:", self.addr),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IntcodeComputer {
     ip: usize,
     rel_base: i64,
     program_len: usize,
+    steps: usize,
+    journal: Option<Vec<JournalEntry>>,
+    recording: Option<Recording>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pending_writes: Vec<MemoryWrite>,
     pub memory: HashMap<usize, i64>,
     pub waiting_for_input: bool,
     pub done: bool,
@@ -85,12 +227,16 @@ impl IntcodeComputer {
             ip: 0,
             rel_base: 0,
             program_len: 0,
+            steps: 0,
+            journal: None,
+            recording: None,
+            pending_writes: Vec::new(),
             memory: HashMap::new(),
             waiting_for_input: false,
             done: true,
         }
     }
-    fn decode(ins: i64) -> (Opcode, Vec<Mode>) {
+    pub(crate) fn decode(ins: i64) -> (Opcode, Vec<Mode>) {
         let opcode = Opcode::from(ins % 100);
         let param_modes = ins
             .to_string()
@@ -101,31 +247,34 @@ impl IntcodeComputer {
             .collect();
         (opcode, param_modes)
     }
+    fn write(&mut self, addr: usize, value: i64) {
+        if self.journal.is_some() {
+            self.pending_writes.push(MemoryWrite {
+                addr,
+                old: self.memory.get(&addr).copied(),
+                new: value,
+            });
+        }
+        self.memory.insert(addr, value);
+    }
+    fn read(&mut self, addr: usize) -> i64 {
+        if !self.memory.contains_key(&addr) {
+            self.write(addr, 0);
+        }
+        self.memory[&addr]
+    }
     fn fetch(&mut self, index: usize, mode: &Mode) -> i64 {
         match mode {
-            Mode::Positional => {
-                self.memory.entry(self.memory[&index] as usize).or_insert(0);
-                self.memory[&(self.memory[&index] as usize)]
-            }
+            Mode::Positional => self.read(self.memory[&index] as usize),
             Mode::Immediate => self.memory[&index],
-            Mode::Relative => {
-                self.memory
-                    .entry((self.rel_base + self.memory[&index]) as usize)
-                    .or_insert(0);
-                self.memory[&((self.rel_base + self.memory[&index]) as usize)]
-            }
+            Mode::Relative => self.read((self.rel_base + self.memory[&index]) as usize),
             _ => panic!("Invalid param mode"),
         }
     }
     fn store(&mut self, index: usize, mode: &Mode, value: i64) {
         match mode {
-            Mode::Positional => {
-                self.memory.insert(self.memory[&index] as usize, value);
-            }
-            Mode::Relative => {
-                self.memory
-                    .insert((self.memory[&index] + self.rel_base) as usize, value);
-            }
+            Mode::Positional => self.write(self.memory[&index] as usize, value),
+            Mode::Relative => self.write((self.memory[&index] + self.rel_base) as usize, value),
             _ => panic!("Invalid mode in store"),
         }
     }
@@ -139,6 +288,48 @@ impl IntcodeComputer {
         self.done = false;
         self.rel_base = 0;
         self.waiting_for_input = false;
+        self.steps = 0;
+        if let Some(journal) = &mut self.journal {
+            journal.clear();
+        }
+        if let Some(recording) = &mut self.recording {
+            recording.events.clear();
+        }
+    }
+    // Number of instructions executed since the program was loaded.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+    // For compiled code, which runs instructions without `step`.
+    pub fn set_steps(&mut self, steps: usize) {
+        self.steps = steps;
+    }
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+    pub fn set_ip(&mut self, ip: usize) {
+        self.ip = ip;
+    }
+    pub fn rel_base(&self) -> i64 {
+        self.rel_base
+    }
+    pub fn set_rel_base(&mut self, rel_base: i64) {
+        self.rel_base = rel_base;
+    }
+    // Address the instruction at `ip` would store to, if it stores at all.
+    pub fn store_addr(&self) -> Option<usize> {
+        let (opcode, pmodes) = Self::decode(*self.memory.get(&self.ip)?);
+        let param = match opcode {
+            Opcode::Add | Opcode::Mul | Opcode::LessThan | Opcode::Equals => 2,
+            Opcode::Input => 0,
+            _ => return None,
+        };
+        let value = *self.memory.get(&(self.ip + param + 1))?;
+        match pmodes.get(param).unwrap_or(&Mode::Positional) {
+            Mode::Positional => Some(value as usize),
+            Mode::Relative => Some((value + self.rel_base) as usize),
+            _ => None,
+        }
     }
     pub fn program_memory(&self) -> Vec<i64> {
         let mut pmem = Vec::new();
@@ -147,107 +338,225 @@ impl IntcodeComputer {
         }
         pmem
     }
-    pub fn execute(&mut self, stdin: &[i64]) -> Vec<i64> {
-        self.waiting_for_input = self.waiting_for_input && stdin.is_empty();
-        let mut stdout = Vec::new();
-        let mut input_index = 0;
-        loop {
-            let (opcode, pmodes) = Self::decode(self.memory[&self.ip]);
-            match opcode {
-                Opcode::Add => {
-                    let op1 = self.fetch(self.ip + 1, pmodes.get(0).unwrap_or(&Mode::Positional));
-                    let op2 = self.fetch(self.ip + 2, pmodes.get(1).unwrap_or(&Mode::Positional));
-
-                    self.store(
-                        self.ip + 3,
-                        pmodes.get(2).unwrap_or(&Mode::Positional),
-                        op1 + op2,
-                    );
-                    self.ip += Opcode::Add.param_len() + 1;
-                }
-                Opcode::Mul => {
-                    let op1 = self.fetch(self.ip + 1, pmodes.get(0).unwrap_or(&Mode::Positional));
-                    let op2 = self.fetch(self.ip + 2, pmodes.get(1).unwrap_or(&Mode::Positional));
-                    self.store(
-                        self.ip + 3,
-                        pmodes.get(2).unwrap_or(&Mode::Positional),
-                        op1 * op2,
-                    );
-                    self.ip += Opcode::Mul.param_len() + 1;
-                }
-                Opcode::Input => {
-                    if input_index >= stdin.len() {
-                        self.waiting_for_input = true;
-                        break;
-                    }
-                    let input: i64 = stdin[input_index];
-                    input_index += 1;
-                    self.store(
-                        self.ip + 1,
-                        pmodes.get(0).unwrap_or(&Mode::Positional),
-                        input,
-                    );
+    // Every cell touched so far, including those created beyond the program
+    // by reads and relative-mode stores.
+    pub fn memory_dump(&self) -> MemoryDump {
+        let mut cells: Vec<(usize, i64)> = self.memory.iter().map(|(a, v)| (*a, *v)).collect();
+        cells.sort_unstable();
+        MemoryDump(cells)
+    }
+    pub fn enable_journal(&mut self) {
+        if self.journal.is_none() {
+            self.journal = Some(Vec::new());
+        }
+    }
+    pub fn disable_journal(&mut self) {
+        self.journal = None;
+    }
+    pub fn journal(&self) -> Option<&[JournalEntry]> {
+        self.journal.as_deref()
+    }
+    pub fn start_recording(&mut self) {
+        if self.recording.is_none() {
+            self.recording = Some(Recording::default());
+        }
+    }
+    pub fn stop_recording(&mut self) -> Option<Recording> {
+        self.recording.take()
+    }
+    pub fn recording(&self) -> Option<&Recording> {
+        self.recording.as_ref()
+    }
+    // Undoes the last journaled instruction and returns its entry.
+    pub fn step_back(&mut self) -> Option<JournalEntry> {
+        let entry = self.journal.as_mut()?.pop()?;
+        for write in entry.writes.iter().rev() {
+            match write.old {
+                Some(value) => self.memory.insert(write.addr, value),
+                None => self.memory.remove(&write.addr),
+            };
+        }
+        self.ip = entry.ip;
+        self.rel_base = entry.rel_base;
+        self.steps = entry.step;
+        if let Some(recording) = &mut self.recording {
+            let steps = self.steps;
+            recording.events.retain(|event| event.step < steps);
+        }
+        self.done = false;
+        self.waiting_for_input = false;
+        Some(entry)
+    }
+    // Rewinds until only `step` instructions remain in the journal.
+    // Returns the inputs that were un-consumed, in their original order.
+    pub fn rewind_to(&mut self, step: usize) -> Vec<i64> {
+        let mut inputs = Vec::new();
+        while self.journal().is_some_and(|j| j.len() > step) {
+            if let Some(input) = self.step_back().and_then(|entry| entry.input) {
+                inputs.push(input);
+            }
+        }
+        inputs.reverse();
+        inputs
+    }
+    pub fn step(&mut self, input: Option<i64>) -> Step {
+        let (ip, rel_base) = (self.ip, self.rel_base);
+        let (opcode, pmodes) = Self::decode(self.memory[&ip]);
+        let mode = |i: usize| pmodes.get(i).unwrap_or(&Mode::Positional);
+        let step = match opcode {
+            Opcode::Add => {
+                let op1 = self.fetch(ip + 1, mode(0));
+                let op2 = self.fetch(ip + 2, mode(1));
+                self.store(ip + 3, mode(2), op1 + op2);
+                self.ip += Opcode::Add.param_len() + 1;
+                Step::Continue
+            }
+            Opcode::Mul => {
+                let op1 = self.fetch(ip + 1, mode(0));
+                let op2 = self.fetch(ip + 2, mode(1));
+                self.store(ip + 3, mode(2), op1 * op2);
+                self.ip += Opcode::Mul.param_len() + 1;
+                Step::Continue
+            }
+            Opcode::Input => match input {
+                Some(input) => {
+                    self.store(ip + 1, mode(0), input);
                     self.ip += Opcode::Input.param_len() + 1;
+                    Step::Input
                 }
-                Opcode::Output => {
-                    let op1 = self.fetch(self.ip + 1, pmodes.get(0).unwrap_or(&Mode::Positional));
-                    stdout.push(op1);
-                    self.ip += Opcode::Output.param_len() + 1;
-                }
-                Opcode::JumpIfTrue => {
-                    let op1 = self.fetch(self.ip + 1, pmodes.get(0).unwrap_or(&Mode::Positional));
-                    let op2 = self.fetch(self.ip + 2, pmodes.get(1).unwrap_or(&Mode::Positional));
-                    if op1 != 0 {
-                        self.ip = op2 as usize;
-                        continue;
-                    }
+                None => return Step::WaitingForInput,
+            },
+            Opcode::Output => {
+                let op1 = self.fetch(ip + 1, mode(0));
+                self.ip += Opcode::Output.param_len() + 1;
+                Step::Output(op1)
+            }
+            Opcode::JumpIfTrue => {
+                let op1 = self.fetch(ip + 1, mode(0));
+                let op2 = self.fetch(ip + 2, mode(1));
+                if op1 != 0 {
+                    self.ip = op2 as usize;
+                } else {
                     self.ip += Opcode::JumpIfTrue.param_len() + 1;
                 }
-                Opcode::JumpIfFalse => {
-                    let op1 = self.fetch(self.ip + 1, pmodes.get(0).unwrap_or(&Mode::Positional));
-                    let op2 = self.fetch(self.ip + 2, pmodes.get(1).unwrap_or(&Mode::Positional));
-                    if op1 == 0 {
-                        self.ip = op2 as usize;
-                        continue;
-                    }
+                Step::Continue
+            }
+            Opcode::JumpIfFalse => {
+                let op1 = self.fetch(ip + 1, mode(0));
+                let op2 = self.fetch(ip + 2, mode(1));
+                if op1 == 0 {
+                    self.ip = op2 as usize;
+                } else {
                     self.ip += Opcode::JumpIfFalse.param_len() + 1;
                 }
-                Opcode::LessThan => {
-                    let op1 = self.fetch(self.ip + 1, pmodes.get(0).unwrap_or(&Mode::Positional));
-                    let op2 = self.fetch(self.ip + 2, pmodes.get(1).unwrap_or(&Mode::Positional));
-                    self.store(
-                        self.ip + 3,
-                        pmodes.get(2).unwrap_or(&Mode::Positional),
-                        if op1 < op2 { 1 } else { 0 },
-                    );
-                    self.ip += Opcode::LessThan.param_len() + 1;
-                }
-                Opcode::Equals => {
-                    let op1 = self.fetch(self.ip + 1, pmodes.get(0).unwrap_or(&Mode::Positional));
-                    let op2 = self.fetch(self.ip + 2, pmodes.get(1).unwrap_or(&Mode::Positional));
-                    self.store(
-                        self.ip + 3,
-                        pmodes.get(2).unwrap_or(&Mode::Positional),
-                        if op1 == op2 { 1 } else { 0 },
-                    );
-                    self.ip += Opcode::Equals.param_len() + 1;
-                }
-                Opcode::RelativeBaseOffset => {
-                    let op1 = self.fetch(self.ip + 1, pmodes.get(0).unwrap_or(&Mode::Positional));
-                    println!("op1 : {}", op1);
-                    self.rel_base += op1;
-                    self.ip += Opcode::RelativeBaseOffset.param_len() + 1;
-                }
-                Opcode::Halt => {
-                    self.done = true;
+                Step::Continue
+            }
+            Opcode::LessThan => {
+                let op1 = self.fetch(ip + 1, mode(0));
+                let op2 = self.fetch(ip + 2, mode(1));
+                self.store(ip + 3, mode(2), if op1 < op2 { 1 } else { 0 });
+                self.ip += Opcode::LessThan.param_len() + 1;
+                Step::Continue
+            }
+            Opcode::Equals => {
+                let op1 = self.fetch(ip + 1, mode(0));
+                let op2 = self.fetch(ip + 2, mode(1));
+                self.store(ip + 3, mode(2), if op1 == op2 { 1 } else { 0 });
+                self.ip += Opcode::Equals.param_len() + 1;
+                Step::Continue
+            }
+            Opcode::RelativeBaseOffset => {
+                let op1 = self.fetch(ip + 1, mode(0));
+                self.rel_base += op1;
+                self.ip += Opcode::RelativeBaseOffset.param_len() + 1;
+                Step::Continue
+            }
+            Opcode::Halt => {
+                self.done = true;
+                return Step::Halted;
+            }
+            Opcode::Invalid => panic!("Invalid opcode"),
+        };
+
+        if let Some(recording) = &mut self.recording {
+            let io = match step {
+                Step::Input => input.map(Io::Input),
+                Step::Output(value) => Some(Io::Output(value)),
+                _ => None,
+            };
+            if let Some(io) = io {
+                recording.events.push(IoEvent {
+                    step: self.steps,
+                    io,
+                });
+            }
+        }
+        self.steps += 1;
+
+        if let Some(journal) = &mut self.journal {
+            journal.push(JournalEntry {
+                step: self.steps - 1,
+                ip,
+                next_ip: self.ip,
+                rel_base,
+                next_rel_base: self.rel_base,
+                writes: std::mem::take(&mut self.pending_writes),
+                input: if step == Step::Input { input } else { None },
+                output: if let Step::Output(value) = step {
+                    Some(value)
+                } else {
+                    None
+                },
+            });
+        }
+        step
+    }
+    pub fn execute(&mut self, stdin: &[i64]) -> Vec<i64> {
+        self.waiting_for_input = self.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut input_index = 0;
+        loop {
+            match self.step(stdin.get(input_index).copied()) {
+                Step::Continue => (),
+                Step::Input => input_index += 1,
+                Step::Output(value) => stdout.push(value),
+                Step::WaitingForInput => {
+                    self.waiting_for_input = true;
                     break;
                 }
-                Opcode::Invalid => panic!("Invalid opcode"),
+                Step::Halted => break,
             }
         }
 
         stdout
     }
+    // Like `execute`, but awaits `input` whenever the program needs a value
+    // and hands outputs to `output` as they are produced. An input source
+    // that yields `None` leaves the machine waiting for input.
+    pub async fn execute_async<I, F, O>(&mut self, mut input: I, mut output: O)
+    where
+        I: FnMut() -> F,
+        F: Future<Output = Option<i64>>,
+        O: FnMut(i64),
+    {
+        self.waiting_for_input = false;
+        loop {
+            match self.step(None) {
+                Step::WaitingForInput => match input().await {
+                    Some(value) => {
+                        self.step(Some(value));
+                    }
+                    None => {
+                        self.waiting_for_input = true;
+                        return;
+                    }
+                },
+                Step::Output(value) => output(value),
+                Step::Halted => return,
+                Step::Continue | Step::Input => (),
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -396,4 +705,286 @@ pub mod tests {
         assert_eq!(computer.rel_base, 19);
         assert_eq!(output[0], 123456789);
     }
+    #[test]
+    fn test_journal_rewind() {
+        let mut computer = IntcodeComputer::new();
+        let program = [
+            109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+        ];
+        computer.enable_journal();
+        computer.load_program(&program);
+        for _ in 0..20 {
+            computer.step(None);
+        }
+        let memory = computer.memory.clone();
+        let (ip, rel_base) = (computer.ip, computer.rel_base);
+
+        let output = computer.execute(&[]);
+        assert_eq!(output.len(), program.len() - 4);
+        assert!(computer.done);
+
+        computer.rewind_to(20);
+        assert_eq!(computer.journal().unwrap().len(), 20);
+        assert_eq!(computer.memory, memory);
+        assert_eq!((computer.ip, computer.rel_base), (ip, rel_base));
+        assert!(!computer.done);
+
+        let mut output = computer.execute(&[]);
+        output.splice(0..0, vec![109, 1, 204, -1]);
+        assert_eq!(output.as_slice(), program);
+
+        computer.rewind_to(0);
+        assert_eq!(computer.program_memory(), program);
+        assert_eq!(computer.memory.len(), program.len());
+    }
+    #[test]
+    fn test_journal_step_back_input() {
+        let mut computer = IntcodeComputer::new();
+        computer.enable_journal();
+        computer.load_program(&[3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8]);
+        assert_eq!(computer.execute(&[8]), vec![1]);
+
+        let entry = computer.step_back().unwrap();
+        assert_eq!(entry.output, Some(1));
+        assert_eq!(entry.writes, vec![]);
+
+        let entry = computer.step_back().unwrap();
+        assert_eq!((entry.ip, entry.next_ip), (2, 6));
+        assert_eq!(
+            entry.writes,
+            vec![MemoryWrite {
+                addr: 9,
+                old: Some(8),
+                new: 1
+            }]
+        );
+        assert_eq!(computer.memory[&9], 8);
+
+        assert_eq!(computer.rewind_to(0), vec![8]);
+        assert_eq!(computer.memory[&9], -1);
+        assert_eq!(computer.execute(&[4]), vec![0]);
+    }
+    #[test]
+    fn test_journal_step_back_steps() {
+        let mut computer = IntcodeComputer::new();
+        computer.load_program(&[3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8]);
+        computer.step(Some(8));
+        computer.enable_journal();
+        computer.execute(&[]);
+        assert_eq!(computer.steps(), 3);
+        assert_eq!(computer.journal().unwrap()[0].step, 1);
+
+        // The counter comes from the journal, whatever it was set to.
+        computer.set_steps(0);
+        assert_eq!(computer.step_back().unwrap().step, 2);
+        assert_eq!(computer.steps(), 2);
+        computer.set_steps(0);
+        computer.rewind_to(0);
+        assert_eq!(computer.steps(), 1);
+        assert_eq!(computer.execute(&[]), vec![1]);
+        assert_eq!(computer.steps(), 3);
+    }
+    #[test]
+    fn test_memory_dump_diff() {
+        let mut computer = IntcodeComputer::new();
+        let program = [109, 19, 204, -4, 21101, 3, 4, 1, 99];
+        computer.load_program(&program);
+        computer.execute(&[]);
+
+        let dump = computer.memory_dump();
+        assert_eq!(dump.get(15), Some(0));
+        assert_eq!(dump.get(20), Some(7));
+        assert_eq!(dump.get(16), None);
+        assert_eq!(dump.0.len(), program.len() + 2);
+
+        let changes = MemoryDump::from_program(&program).diff(&dump);
+        assert_eq!(
+            changes,
+            vec![
+                CellChange {
+                    addr: 15,
+                    before: None,
+                    after: Some(0)
+                },
+                CellChange {
+                    addr: 20,
+                    before: None,
+                    after: Some(7)
+                },
+            ]
+        );
+        assert_eq!(changes[1].to_string(), "+      20: 7");
+
+        computer.load_program(&[1, 0, 0, 0, 99]);
+        computer.execute(&[]);
+        let changes = dump.diff(&computer.memory_dump());
+        assert_eq!(changes[0].to_string(), "~       0: 109 -> 2");
+        assert_eq!(changes[changes.len() - 1].to_string(), "-      20: 7");
+        assert_eq!(changes.len(), 11);
+    }
+    mod executor {
+        use std::cell::RefCell;
+        use std::collections::VecDeque;
+        use std::future::{self, Future};
+        use std::pin::Pin;
+        use std::rc::Rc;
+        use std::sync::Arc;
+        use std::task::{Context, Poll, Wake, Waker};
+
+        struct Noop;
+
+        impl Wake for Noop {
+            fn wake(self: Arc<Self>) {}
+        }
+
+        pub fn waker() -> Waker {
+            Waker::from(Arc::new(Noop))
+        }
+
+        pub type Task<'a> = Pin<Box<dyn Future<Output = ()> + 'a>>;
+
+        // Polls every task in turn until all of them have completed.
+        pub fn run(mut tasks: Vec<Task>) {
+            let waker = waker();
+            let mut cx = Context::from_waker(&waker);
+            while !tasks.is_empty() {
+                tasks.retain_mut(|task| task.as_mut().poll(&mut cx).is_pending());
+            }
+        }
+
+        #[derive(Default)]
+        pub struct Pipe {
+            pub queue: VecDeque<i64>,
+            pub closed: bool,
+        }
+
+        pub type Shared = Rc<RefCell<Pipe>>;
+
+        pub fn recv(pipe: &Shared) -> impl Future<Output = Option<i64>> {
+            let pipe = pipe.clone();
+            future::poll_fn(move |_| {
+                let mut pipe = pipe.borrow_mut();
+                match pipe.queue.pop_front() {
+                    Some(value) => Poll::Ready(Some(value)),
+                    None if pipe.closed => Poll::Ready(None),
+                    None => Poll::Pending,
+                }
+            })
+        }
+    }
+    #[test]
+    fn test_execute_async_awaits_input() {
+        use executor::*;
+        use std::future::Future;
+        use std::task::{Context, Poll};
+
+        let waker = waker();
+        let mut cx = Context::from_waker(&waker);
+
+        let mut computer = IntcodeComputer::new();
+        computer.load_program(&[3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8]);
+        let pipe = Shared::default();
+        let mut output = Vec::new();
+        {
+            let mut run = Box::pin(computer.execute_async(|| recv(&pipe), |v| output.push(v)));
+            assert_eq!(run.as_mut().poll(&mut cx), Poll::Pending);
+            assert_eq!(run.as_mut().poll(&mut cx), Poll::Pending);
+            pipe.borrow_mut().queue.push_back(8);
+            assert_eq!(run.as_mut().poll(&mut cx), Poll::Ready(()));
+        }
+        assert_eq!(output, vec![1]);
+        assert!(computer.done);
+
+        computer.load_program(&[3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8]);
+        pipe.borrow_mut().closed = true;
+        run(vec![Box::pin(
+            computer.execute_async(|| recv(&pipe), |_| ()),
+        )]);
+        assert!(computer.waiting_for_input);
+        assert!(!computer.done);
+        assert_eq!(computer.execute(&[3]), vec![0]);
+    }
+    #[test]
+    fn test_execute_async_feedback_loop() {
+        use executor::*;
+
+        let program = [
+            3, 26, 1001, 26, -4, 26, 3, 27, 1002, 27, 2, 27, 1, 27, 26, 27, 4, 27, 1001, 28, -1,
+            28, 1005, 28, 6, 99, 0, 0, 5,
+        ];
+        let phases = [9, 8, 7, 6, 5];
+        let pipes: Vec<Shared> = phases
+            .iter()
+            .map(|phase| {
+                let pipe = Shared::default();
+                pipe.borrow_mut().queue.push_back(*phase);
+                pipe
+            })
+            .collect();
+        pipes[0].borrow_mut().queue.push_back(0);
+
+        let mut amps: Vec<IntcodeComputer> = phases
+            .iter()
+            .map(|_| {
+                let mut amp = IntcodeComputer::new();
+                amp.load_program(&program);
+                amp
+            })
+            .collect();
+        let tasks: Vec<Task> = amps
+            .iter_mut()
+            .enumerate()
+            .map(|(i, amp)| {
+                let input = pipes[i].clone();
+                let output = pipes[(i + 1) % pipes.len()].clone();
+                let task = amp.execute_async(
+                    move || recv(&input),
+                    move |value| output.borrow_mut().queue.push_back(value),
+                );
+                Box::pin(task) as Task
+            })
+            .collect();
+        run(tasks);
+
+        assert!(amps.iter().all(|amp| amp.done));
+        assert_eq!(pipes[0].borrow().queue, vec![139_629_729]);
+    }
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let mut computer = IntcodeComputer::new();
+        computer.enable_journal();
+        computer.load_program(&[
+            3, 26, 1001, 26, -4, 26, 3, 27, 1002, 27, 2, 27, 1, 27, 26, 27, 4, 27, 1001, 28, -1,
+            28, 1005, 28, 6, 99, 0, 0, 5,
+        ]);
+        assert_eq!(computer.execute(&[9, 0]), vec![5]);
+        assert!(computer.waiting_for_input);
+
+        let json = serde_json::to_string(&computer).unwrap();
+        let bytes = bincode::serialize(&computer).unwrap();
+        let mut restored: Vec<IntcodeComputer> = vec![
+            serde_json::from_str(&json).unwrap(),
+            bincode::deserialize(&bytes).unwrap(),
+        ];
+        let output = computer.execute(&[7]);
+        for copy in restored.iter_mut() {
+            assert!(copy.waiting_for_input);
+            assert_eq!(copy.execute(&[7]), output);
+            assert_eq!(copy.memory_dump(), computer.memory_dump());
+            assert_eq!(copy.journal(), computer.journal());
+        }
+
+        let trace = computer.journal().unwrap().to_vec();
+        let json = serde_json::to_string(&trace).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Vec<JournalEntry>>(&json).unwrap(),
+            trace
+        );
+        let bytes = bincode::serialize(&Step::Output(-3)).unwrap();
+        assert_eq!(
+            bincode::deserialize::<Step>(&bytes).unwrap(),
+            Step::Output(-3)
+        );
+    }
 }
@@ -1,4 +1,17 @@
+pub mod aot;
+pub mod cfg;
+pub mod differential;
+pub mod fuel;
 pub mod intcode;
+pub mod ocr;
+pub mod orbit;
+pub mod password;
+pub mod raster;
+pub mod recording;
+pub mod rng;
+pub mod sif;
+pub mod wire;
+pub mod wire_grid;
 pub use intcode::*;
 
 //#[macro_use]
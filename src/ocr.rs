@@ -0,0 +1,231 @@
+use crate::sif::{Layer, BLACK, WHITE};
+use std::fmt;
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+// Y is the one glyph wider than the rest.
+const WIDE_GLYPH_WIDTH: usize = 5;
+
+// The capital letters of the 4×6 font that puzzle answers are drawn in,
+// `#` for lit pixels, row by row. Glyphs are separated by one blank column.
+const FONT: [(char, &str); 18] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('I', "###..#...#...#...#..###."),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Y', "#...##...#.#.#...#....#....#.."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    Height(usize),
+    // What could be read, with `?` for each glyph not in the font, and
+    // those glyphs drawn as in `FONT` with their position.
+    Unknown {
+        text: String,
+        glyphs: Vec<(usize, String)>,
+    },
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Height(height) => {
+                write!(f, "glyphs are {} pixels high, not {}", GLYPH_HEIGHT, height)
+            }
+            Self::Unknown { text, glyphs } => {
+                write!(f, "unknown glyphs in {:?}:", text)?;
+                for (i, glyph) in glyphs.iter() {
+                    write!(f, "\n#{}:", i)?;
+                    for row in glyph.as_bytes().chunks(GLYPH_WIDTH) {
+                        write!(f, "\n{}", String::from_utf8_lossy(row))?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+// White pixels of the `width` columns starting at `x`, blank past the edge.
+fn glyph(layer: &Layer, x: usize, width: usize) -> String {
+    let mut glyph = String::with_capacity(width * GLYPH_HEIGHT);
+    for y in 0..GLYPH_HEIGHT {
+        for dx in 0..width {
+            let lit = x + dx < layer.width() && layer.get(x + dx, y) == WHITE;
+            glyph.push(if lit { '#' } else { '.' });
+        }
+    }
+    glyph
+}
+
+pub fn recognize(glyph: &str) -> Option<char> {
+    FONT.iter().find(|(_, g)| *g == glyph).map(|(c, _)| *c)
+}
+
+// `text` in white on black, in the layout `ocr` reads; None if it is empty
+// or the font lacks one of the letters.
+pub fn draw(text: &str) -> Option<Layer> {
+    let glyphs = text
+        .chars()
+        .map(|c| {
+            FONT.iter()
+                .find(|(f, _)| *f == c)
+                .map(|(_, g)| g.as_bytes())
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let width = glyphs
+        .iter()
+        .map(|glyph| glyph.len() / GLYPH_HEIGHT + 1)
+        .sum::<usize>()
+        .checked_sub(1)?;
+    let mut pixels = vec![BLACK; width * GLYPH_HEIGHT];
+    let mut x = 0;
+    for glyph in glyphs.iter() {
+        let glyph_width = glyph.len() / GLYPH_HEIGHT;
+        for (j, &pixel) in glyph.iter().enumerate() {
+            if pixel == b'#' {
+                pixels[j / glyph_width * width + x + j % glyph_width] = WHITE;
+            }
+        }
+        x += glyph_width + 1;
+    }
+    Layer::new(width, GLYPH_HEIGHT, pixels).ok()
+}
+
+// Reads the letters of a flattened image, white on black, from the left
+// edge: a glyph every five columns, or six after a Y.
+pub fn ocr(layer: &Layer) -> Result<String, OcrError> {
+    if layer.height() != GLYPH_HEIGHT {
+        return Err(OcrError::Height(layer.height()));
+    }
+    let mut text = String::new();
+    let mut unknown = Vec::new();
+    let mut x = 0;
+    while x < layer.width() {
+        if let Some(c) = recognize(&glyph(layer, x, WIDE_GLYPH_WIDTH)) {
+            text.push(c);
+            x += WIDE_GLYPH_WIDTH + 1;
+            continue;
+        }
+        let glyph = glyph(layer, x, GLYPH_WIDTH);
+        match recognize(&glyph) {
+            Some(c) => text.push(c),
+            None => {
+                unknown.push((text.chars().count(), glyph));
+                text.push('?');
+            }
+        }
+        x += GLYPH_WIDTH + 1;
+    }
+    if unknown.is_empty() {
+        Ok(text)
+    } else {
+        Err(OcrError::Unknown {
+            text,
+            glyphs: unknown,
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    // Draws `#` as white on black.
+    fn draw(rows: &[&str]) -> Layer {
+        let pixels = rows
+            .iter()
+            .flat_map(|row| row.chars().map(|c| if c == '#' { WHITE } else { BLACK }))
+            .collect();
+        Layer::new(rows[0].len(), rows.len(), pixels).unwrap()
+    }
+
+    #[test]
+    fn test_ocr() {
+        let layer = draw(&[
+            "####.###..#..#..##..###..",
+            "#....#..#.#..#.#..#.#..#.",
+            "###..#..#.#..#.#..#.#..#.",
+            "#....###..#..#.####.###..",
+            "#....#....#..#.#..#.#.#..",
+            "#....#.....##..#..#.#..#.",
+        ]);
+        assert_eq!(ocr(&layer), Ok("FPUAR".to_string()));
+        for (c, glyph) in FONT.iter() {
+            let width = glyph.len() / GLYPH_HEIGHT;
+            let rows: Vec<&str> = (0..GLYPH_HEIGHT)
+                .map(|y| &glyph[width * y..width * (y + 1)])
+                .collect();
+            assert_eq!(ocr(&draw(&rows)), Ok(c.to_string()));
+        }
+
+        let layer = draw(&[
+            "#...#.###..#..#.####",
+            "#...#..#...#..#.#...",
+            ".#.#...#...####.###.",
+            "..#....#...#..#.#...",
+            "..#....#...#..#.#...",
+            "..#...###..#..#.####",
+        ]);
+        assert_eq!(ocr(&layer), Ok("YIHE".to_string()));
+    }
+    #[test]
+    fn test_unknown_glyphs() {
+        let layer = draw(&[
+            "#..#.#...#",
+            "#..#.##..#",
+            "####.#.#.#",
+            "#..#.#..##",
+            "#..#.#...#",
+            "#..#.#...#",
+        ]);
+        let error = ocr(&layer).unwrap_err();
+        assert_eq!(
+            error,
+            OcrError::Unknown {
+                text: "H?".to_string(),
+                glyphs: vec![(1, "#...##..#.#.#..##...#...".to_string())],
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "unknown glyphs in \"H?\":\n#1:\n#...\n##..\n#.#.\n#..#\n#...\n#..."
+        );
+        assert_eq!(
+            ocr(&Layer::filled(4, 5, BLACK).unwrap()),
+            Err(OcrError::Height(5))
+        );
+    }
+    #[test]
+    fn test_draw() {
+        let drawn = super::draw("FPUAR").unwrap();
+        assert_eq!(drawn.width(), 24);
+        assert_eq!(ocr(&drawn), Ok("FPUAR".to_string()));
+        assert_eq!(
+            drawn.rows().next().unwrap()[..10],
+            [1, 1, 1, 1, 0, 1, 1, 1, 0, 0]
+        );
+        assert_eq!(super::draw("FPQ"), None);
+
+        let drawn = super::draw("AYIY").unwrap();
+        assert_eq!(drawn.width(), 4 + 1 + 5 + 1 + 4 + 1 + 5);
+        assert_eq!(ocr(&drawn), Ok("AYIY".to_string()));
+        assert_eq!(super::draw(""), None);
+    }
+}
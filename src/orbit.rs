@@ -0,0 +1,598 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+// Why a map is not a single tree; lines are 1-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrbitError {
+    Malformed {
+        line: usize,
+        text: String,
+    },
+    TwoParents {
+        line: usize,
+        object: String,
+        parents: (String, String),
+    },
+    // Objects that orbit each other in a loop, in orbit order.
+    Cycle {
+        objects: Vec<String>,
+    },
+    // The roots of each separate tree.
+    MultipleRoots {
+        roots: Vec<String>,
+    },
+    // A query named an object that is not on the map.
+    Unknown {
+        name: String,
+    },
+    // A query needed the center of the object at the root of the map.
+    NoCenter {
+        name: String,
+    },
+}
+
+// Objects of a day 6 map, each orbiting at most one parent. Depths and
+// subtree sizes are worked out once, on construction.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct OrbitMap {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    parents: Vec<Option<usize>>,
+    children: Vec<Vec<usize>>,
+    depths: Vec<usize>,
+    // Objects in the subtree rooted at each object, itself included.
+    sizes: Vec<usize>,
+    // An ancestor at most twice as far up as the previous jump, so that any
+    // ancestor is O(log depth) jumps and parent steps away.
+    jumps: Vec<usize>,
+}
+
+impl fmt::Display for OrbitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Malformed { line, text } => {
+                write!(
+                    f,
+                    "line {}: expected `CENTER)ORBITER`, got `{}`",
+                    line, text
+                )
+            }
+            Self::TwoParents {
+                line,
+                object,
+                parents,
+            } => write!(
+                f,
+                "line {}: {} orbits both {} and {}",
+                line, object, parents.0, parents.1
+            ),
+            Self::Cycle { objects } => {
+                write!(f, "orbit cycle {} -> {}", objects.join(" -> "), objects[0])
+            }
+            Self::MultipleRoots { roots } => {
+                write!(f, "disconnected maps rooted at {}", roots.join(", "))
+            }
+            Self::Unknown { name } => write!(f, "no object named {}", name),
+            Self::NoCenter { name } => write!(f, "{} does not orbit anything", name),
+        }
+    }
+}
+
+impl std::error::Error for OrbitError {}
+
+impl OrbitMap {
+    // `(center, orbiter)` pairs, as in `COM)B`; errors refer to pairs by
+    // their 1-based position. Repeated pairs are ignored.
+    pub fn from_orbits<'a, I: IntoIterator<Item = (&'a str, &'a str)>>(
+        orbits: I,
+    ) -> Result<Self, OrbitError> {
+        let mut map = Self::default();
+        for (i, (center, orbiter)) in orbits.into_iter().enumerate() {
+            let center = map.add(center);
+            let orbiter = map.add(orbiter);
+            match map.parents[orbiter] {
+                Some(parent) if parent == center => continue,
+                Some(parent) => {
+                    return Err(OrbitError::TwoParents {
+                        line: i + 1,
+                        object: map.names[orbiter].clone(),
+                        parents: (map.names[parent].clone(), map.names[center].clone()),
+                    })
+                }
+                None => {}
+            }
+            map.parents[orbiter] = Some(center);
+            map.children[center].push(orbiter);
+        }
+        map.measure()?;
+        Ok(map)
+    }
+    // One `CENTER)ORBITER` pair per line, blank lines aside.
+    pub fn parse(input: &str) -> Result<Self, OrbitError> {
+        let mut orbits = Vec::new();
+        let mut lines = Vec::new();
+        for (i, line) in input.lines().enumerate() {
+            let text = line.trim();
+            if text.is_empty() {
+                continue;
+            }
+            match text.split_once(')') {
+                Some((center, orbiter))
+                    if !center.is_empty() && !orbiter.is_empty() && !orbiter.contains(')') =>
+                {
+                    orbits.push((center, orbiter));
+                    lines.push(i + 1);
+                }
+                _ => {
+                    return Err(OrbitError::Malformed {
+                        line: i + 1,
+                        text: text.to_string(),
+                    })
+                }
+            }
+        }
+        Self::from_orbits(orbits).map_err(|e| match e {
+            OrbitError::TwoParents {
+                line,
+                object,
+                parents,
+            } => OrbitError::TwoParents {
+                line: lines[line - 1],
+                object,
+                parents,
+            },
+            e => e,
+        })
+    }
+    fn add(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.parents.push(None);
+        self.children.push(Vec::new());
+        id
+    }
+    // Breadth first from the roots, so every object comes after its parent;
+    // sizes then add up in reverse. Objects the roots do not reach hang off
+    // a cycle.
+    fn measure(&mut self) -> Result<(), OrbitError> {
+        let n = self.names.len();
+        self.depths = vec![0; n];
+        self.sizes = vec![1; n];
+        self.jumps = (0..n).collect();
+        let mut order: Vec<usize> = (0..n).filter(|&id| self.parents[id].is_none()).collect();
+        let mut next = 0;
+        while next < order.len() {
+            let id = order[next];
+            next += 1;
+            let jump = self.jumps[id];
+            let skip = if self.depths[id] - self.depths[jump]
+                == self.depths[jump] - self.depths[self.jumps[jump]]
+            {
+                self.jumps[jump]
+            } else {
+                id
+            };
+            for &child in self.children[id].iter() {
+                self.depths[child] = self.depths[id] + 1;
+                self.jumps[child] = skip;
+                order.push(child);
+            }
+        }
+        if order.len() < n {
+            let mut reached = vec![false; n];
+            for &id in order.iter() {
+                reached[id] = true;
+            }
+            let mut id = (0..n).find(|&id| !reached[id]).unwrap();
+            // Walk up far enough to be on the cycle itself.
+            for _ in 0..n {
+                id = self.parents[id].unwrap();
+            }
+            let mut objects = vec![self.names[id].clone()];
+            let mut center = self.parents[id].unwrap();
+            while center != id {
+                objects.push(self.names[center].clone());
+                center = self.parents[center].unwrap();
+            }
+            objects.reverse();
+            return Err(OrbitError::Cycle { objects });
+        }
+        let roots: Vec<String> = (0..n)
+            .filter(|&id| self.parents[id].is_none())
+            .map(|id| self.names[id].clone())
+            .collect();
+        if roots.len() > 1 {
+            return Err(OrbitError::MultipleRoots { roots });
+        }
+        for &id in order.iter().rev() {
+            if let Some(parent) = self.parents[id] {
+                self.sizes[parent] += self.sizes[id];
+            }
+        }
+        Ok(())
+    }
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.names.iter().map(String::as_str)
+    }
+    pub fn contains(&self, name: &str) -> bool {
+        self.ids.contains_key(name)
+    }
+    pub fn parent(&self, name: &str) -> Option<&str> {
+        let parent = self.parents[*self.ids.get(name)?]?;
+        Some(&self.names[parent])
+    }
+    pub fn children(&self, name: &str) -> Vec<&str> {
+        match self.ids.get(name) {
+            Some(&id) => self.children[id]
+                .iter()
+                .map(|&child| self.names[child].as_str())
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+    // Direct and indirect orbits of one object.
+    pub fn depth(&self, name: &str) -> Option<usize> {
+        Some(self.depths[*self.ids.get(name)?])
+    }
+    pub fn subtree_size(&self, name: &str) -> Option<usize> {
+        Some(self.sizes[*self.ids.get(name)?])
+    }
+    pub fn direct_orbits(&self) -> u64 {
+        self.parents.iter().filter(|p| p.is_some()).count() as u64
+    }
+    pub fn indirect_orbits(&self) -> u64 {
+        self.total_orbits() - self.direct_orbits()
+    }
+    pub fn total_orbits(&self) -> u64 {
+        self.depths.iter().map(|&d| d as u64).sum()
+    }
+    fn id(&self, name: &str) -> Result<usize, OrbitError> {
+        self.ids
+            .get(name)
+            .copied()
+            .ok_or_else(|| OrbitError::Unknown {
+                name: name.to_string(),
+            })
+    }
+    fn ancestor_at(&self, mut id: usize, depth: usize) -> usize {
+        while self.depths[id] > depth {
+            id = if self.depths[self.jumps[id]] >= depth {
+                self.jumps[id]
+            } else {
+                self.parents[id].unwrap()
+            };
+        }
+        id
+    }
+    fn common_ancestor(&self, a: usize, b: usize) -> usize {
+        let depth = self.depths[a].min(self.depths[b]);
+        let (mut a, mut b) = (self.ancestor_at(a, depth), self.ancestor_at(b, depth));
+        // At equal depths the jumps are equally long.
+        while a != b {
+            if self.jumps[a] != self.jumps[b] {
+                a = self.jumps[a];
+                b = self.jumps[b];
+            } else {
+                a = self.parents[a].unwrap();
+                b = self.parents[b].unwrap();
+            }
+        }
+        a
+    }
+    // The deepest object both objects orbit, directly or not, or one of them
+    // if it orbits the other.
+    pub fn common_center(&self, a: &str, b: &str) -> Result<&str, OrbitError> {
+        let center = self.common_ancestor(self.id(a)?, self.id(b)?);
+        Ok(&self.names[center])
+    }
+    // Moves from one object to a neighbouring center or orbiter needed to
+    // get from `from` to `to`. Day 6 asks for the transfers between the
+    // centers of YOU and SAN, two less than between YOU and SAN themselves.
+    pub fn transfers(&self, from: &str, to: &str) -> Result<usize, OrbitError> {
+        let (a, b) = (self.id(from)?, self.id(to)?);
+        let center = self.common_ancestor(a, b);
+        Ok(self.depths[a] + self.depths[b] - 2 * self.depths[center])
+    }
+    // Day 6 part 2: transfers between the objects `from` and `to` orbit.
+    pub fn orbital_transfers(&self, from: &str, to: &str) -> Result<usize, OrbitError> {
+        let center = |name: &str| {
+            self.parents[self.id(name)?]
+                .map(|parent| self.names[parent].as_str())
+                .ok_or_else(|| OrbitError::NoCenter {
+                    name: name.to_string(),
+                })
+        };
+        self.transfers(center(from)?, center(to)?)
+    }
+    pub fn transfers_batch<'a, I: IntoIterator<Item = (&'a str, &'a str)>>(
+        &self,
+        queries: I,
+    ) -> Result<Vec<usize>, OrbitError> {
+        queries
+            .into_iter()
+            .map(|(from, to)| self.transfers(from, to))
+            .collect()
+    }
+    // Every object visited from `from` to `to`, both included.
+    pub fn transfer_path(&self, from: &str, to: &str) -> Result<Vec<&str>, OrbitError> {
+        let (a, b) = (self.id(from)?, self.id(to)?);
+        let center = self.common_ancestor(a, b);
+        let up = |mut id: usize| {
+            let mut path = Vec::new();
+            while id != center {
+                path.push(self.names[id].as_str());
+                id = self.parents[id].unwrap();
+            }
+            path
+        };
+        let mut path = up(a);
+        path.push(&self.names[center]);
+        path.extend(up(b).into_iter().rev());
+        Ok(path)
+    }
+    // Graphviz source with an edge from each center to its orbiters, and the
+    // transfer path between `highlight`ed objects in red.
+    pub fn to_dot(&self, highlight: Option<(&str, &str)>) -> Result<String, OrbitError> {
+        let path: HashSet<&str> = match highlight {
+            Some((from, to)) => self.transfer_path(from, to)?.into_iter().collect(),
+            None => HashSet::new(),
+        };
+        let on_path = |name: &str| path.contains(&name);
+        let mut dot =
+            String::from("digraph orbits {\n    node [shape=ellipse, fontname=monospace];\n");
+        for name in self.names() {
+            let style = if on_path(name) {
+                " [color=red, fontcolor=red, penwidth=2]"
+            } else {
+                ""
+            };
+            dot.push_str(&format!("    {}{};\n", quote(name), style));
+        }
+        for (id, parent) in self.parents.iter().enumerate() {
+            if let Some(parent) = *parent {
+                let (center, orbiter) = (&self.names[parent], &self.names[id]);
+                let style = if on_path(center) && on_path(orbiter) {
+                    " [color=red, penwidth=2]"
+                } else {
+                    ""
+                };
+                dot.push_str(&format!(
+                    "    {} -> {}{};\n",
+                    quote(center),
+                    quote(orbiter),
+                    style
+                ));
+            }
+        }
+        dot.push_str("}\n");
+        Ok(dot)
+    }
+    // One object per line, indented under its center and followed by its
+    // depth.
+    pub fn to_tree(&self) -> String {
+        let mut tree = String::new();
+        let mut stack: Vec<usize> = (0..self.len())
+            .rev()
+            .filter(|&id| self.parents[id].is_none())
+            .collect();
+        while let Some(id) = stack.pop() {
+            let depth = self.depths[id];
+            tree.push_str(&format!(
+                "{:indent$}{} ({})\n",
+                "",
+                self.names[id],
+                depth,
+                indent = 2 * depth
+            ));
+            stack.extend(self.children[id].iter().rev());
+        }
+        tree
+    }
+}
+
+// A DOT string holding `name`, which may contain quotes and backslashes.
+fn quote(name: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in name.chars() {
+        if c == '"' || c == '\\' {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    pub const EXAMPLE: &str = "COM)B\nB)C\nC)D\nD)E\nE)F\nB)G\nG)H\nD)I\nE)J\nJ)K\nK)L\n";
+
+    #[test]
+    fn test_example() {
+        let map = OrbitMap::parse(EXAMPLE).unwrap();
+        assert_eq!(map.len(), 12);
+        assert_eq!(map.total_orbits(), 42);
+        assert_eq!(map.direct_orbits(), 11);
+        assert_eq!(map.indirect_orbits(), 31);
+        assert_eq!(map.depth("D"), Some(3));
+        assert_eq!(map.depth("L"), Some(7));
+        assert_eq!(map.depth("COM"), Some(0));
+        assert_eq!(map.depth("X"), None);
+        assert_eq!(map.parent("L"), Some("K"));
+        assert_eq!(map.parent("COM"), None);
+        assert_eq!(map.children("B"), ["C", "G"]);
+        assert_eq!(map.subtree_size("COM"), Some(12));
+        assert_eq!(map.subtree_size("E"), Some(5));
+        assert_eq!(map.subtree_size("L"), Some(1));
+    }
+    #[test]
+    fn test_deep_chain() {
+        let names: Vec<String> = (0..200_000).map(|i| format!("N{}", i)).collect();
+        let map = OrbitMap::from_orbits(names.windows(2).map(|w| (w[0].as_str(), w[1].as_str())))
+            .unwrap();
+        assert_eq!(map.depth("N199999"), Some(199_999));
+        assert_eq!(map.subtree_size("N0"), Some(200_000));
+        assert_eq!(map.total_orbits(), 199_999 * 200_000 / 2);
+    }
+    #[test]
+    fn test_invalid_maps() {
+        let error = |input| OrbitMap::parse(input).unwrap_err().to_string();
+        assert_eq!(
+            error("COM)B\nB-C"),
+            "line 2: expected `CENTER)ORBITER`, got `B-C`"
+        );
+        assert_eq!(
+            OrbitMap::parse("COM)B\n\nB)"),
+            Err(OrbitError::Malformed {
+                line: 3,
+                text: "B)".to_string()
+            })
+        );
+        assert_eq!(
+            error("COM)B\nB)C)D"),
+            "line 2: expected `CENTER)ORBITER`, got `B)C)D`"
+        );
+        assert_eq!(
+            OrbitMap::parse("COM)B\n\nB)C\nCOM)C"),
+            Err(OrbitError::TwoParents {
+                line: 4,
+                object: "C".to_string(),
+                parents: ("B".to_string(), "COM".to_string())
+            })
+        );
+        assert_eq!(
+            error("COM)B\nB)C\nX)Y\nY)Z\nZ)X\nZ)W"),
+            "orbit cycle X -> Y -> Z -> X"
+        );
+        assert_eq!(error("A)A"), "orbit cycle A -> A");
+        assert_eq!(
+            OrbitMap::parse("COM)B\nX)Y\nB)C\nOTHER)X"),
+            Err(OrbitError::MultipleRoots {
+                roots: vec!["COM".to_string(), "OTHER".to_string()]
+            })
+        );
+        assert_eq!(OrbitMap::parse("COM)B\nCOM)B").unwrap().len(), 2);
+        assert!(OrbitMap::parse("").unwrap().is_empty());
+    }
+    #[test]
+    fn test_transfers() {
+        let map = OrbitMap::parse(&format!("{}K)YOU\nI)SAN\n", EXAMPLE)).unwrap();
+        assert_eq!(map.transfers("YOU", "SAN"), Ok(6));
+        assert_eq!(map.orbital_transfers("YOU", "SAN"), Ok(4));
+        // YOU orbiting SAN, or the other way round, is one transfer away.
+        assert_eq!(map.orbital_transfers("K", "YOU"), Ok(1));
+        assert_eq!(map.orbital_transfers("YOU", "K"), Ok(1));
+        assert_eq!(map.orbital_transfers("YOU", "YOU"), Ok(0));
+        assert_eq!(
+            map.orbital_transfers("COM", "SAN").unwrap_err().to_string(),
+            "COM does not orbit anything"
+        );
+        assert_eq!(
+            map.transfer_path("YOU", "SAN").unwrap(),
+            ["YOU", "K", "J", "E", "D", "I", "SAN"]
+        );
+        assert_eq!(map.common_center("YOU", "SAN"), Ok("D"));
+        assert_eq!(map.common_center("L", "E"), Ok("E"));
+        assert_eq!(map.transfer_path("E", "L").unwrap(), ["E", "J", "K", "L"]);
+        assert_eq!(map.transfer_path("H", "H").unwrap(), ["H"]);
+        assert_eq!(
+            map.transfers_batch(vec![("YOU", "SAN"), ("COM", "L"), ("H", "F")]),
+            Ok(vec![6, 7, 6])
+        );
+        assert_eq!(
+            map.transfers("YOU", "SANTA"),
+            Err(OrbitError::Unknown {
+                name: "SANTA".to_string()
+            })
+        );
+        assert_eq!(
+            map.transfers_batch(vec![("YOU", "SAN"), ("X", "L")])
+                .unwrap_err()
+                .to_string(),
+            "no object named X"
+        );
+    }
+    #[test]
+    fn test_transfers_match_walking_up() {
+        // A random tree, checked against walking parents one by one.
+        let mut rng = crate::rng::Rng::new(6);
+        let names: Vec<String> = (0..2000).map(|i| format!("O{}", i)).collect();
+        let orbits: Vec<(&str, &str)> = (1..names.len())
+            .map(|i| {
+                // Mostly long chains, with some branching.
+                let center = if rng.below(8) == 0 {
+                    rng.below(i)
+                } else {
+                    i - 1
+                };
+                (names[center].as_str(), names[i].as_str())
+            })
+            .collect();
+        let map = OrbitMap::from_orbits(orbits).unwrap();
+        let ancestors = |name: &str| {
+            let mut path = vec![name.to_string()];
+            while let Some(parent) = map.parent(path.last().unwrap()) {
+                path.push(parent.to_string());
+            }
+            path
+        };
+        for _ in 0..500 {
+            let a = &names[rng.below(names.len())];
+            let b = &names[rng.below(names.len())];
+            let (up_a, up_b) = (ancestors(a), ancestors(b));
+            let center = up_a.iter().find(|name| up_b.contains(name)).unwrap();
+            assert_eq!(map.common_center(a, b), Ok(center.as_str()));
+            let expected = up_a.iter().position(|n| n == center).unwrap()
+                + up_b.iter().position(|n| n == center).unwrap();
+            assert_eq!(map.transfers(a, b), Ok(expected));
+            assert_eq!(map.transfer_path(a, b).unwrap().len(), expected + 1);
+        }
+    }
+    #[test]
+    fn test_to_tree() {
+        let map = OrbitMap::parse("COM)B\nB)C\nCOM)D\nB)E\n").unwrap();
+        assert_eq!(
+            map.to_tree(),
+            "COM (0)\n  B (1)\n    C (2)\n    E (2)\n  D (1)\n"
+        );
+        assert_eq!(OrbitMap::default().to_tree(), "");
+    }
+    #[test]
+    fn test_to_dot() {
+        let map = OrbitMap::parse(&format!("{}K)YOU\nI)SAN\n", EXAMPLE)).unwrap();
+        let dot = map.to_dot(None).unwrap();
+        assert!(dot.starts_with("digraph orbits {"));
+        assert!(dot.contains("    \"COM\" -> \"B\";\n"));
+        assert_eq!(dot.matches(" -> ").count(), 13);
+        assert!(!dot.contains("red"));
+
+        let dot = map.to_dot(Some(("YOU", "SAN"))).unwrap();
+        assert!(dot.contains("    \"D\" [color=red, fontcolor=red, penwidth=2];\n"));
+        assert!(dot.contains("    \"D\" -> \"E\" [color=red, penwidth=2];\n"));
+        assert!(dot.contains("    \"C\" -> \"D\";\n"));
+        assert_eq!(dot.matches("[color=red, penwidth=2]").count(), 6);
+        assert_eq!(
+            map.to_dot(Some(("YOU", "NOBODY"))),
+            Err(OrbitError::Unknown {
+                name: "NOBODY".to_string()
+            })
+        );
+
+        let map = OrbitMap::parse("COM)a\"b\nCOM)c\\\n").unwrap();
+        let dot = map.to_dot(None).unwrap();
+        assert!(dot.contains("    \"COM\" -> \"a\\\"b\";\n"));
+        assert!(dot.contains("    \"COM\" -> \"c\\\\\";\n"));
+    }
+}
@@ -0,0 +1,494 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+pub type Predicate = Arc<dyn Fn(&[u8]) -> bool + Send + Sync>;
+
+// A condition on the decimal digits of a candidate password, most
+// significant digit first.
+#[derive(Clone)]
+pub enum Rule {
+    Length(usize),
+    // Every digit lies in `lo..=hi`.
+    DigitRange(u8, u8),
+    NonDecreasing,
+    // Some digit repeats at least this many times in a row.
+    RunAtLeast(usize),
+    // Some group of equal adjacent digits is exactly this long.
+    RunExactly(usize),
+    Custom(Predicate),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RuleSet {
+    pub rules: Vec<Rule>,
+}
+
+impl fmt::Debug for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Length(n) => write!(f, "Length({})", n),
+            Self::DigitRange(lo, hi) => write!(f, "DigitRange({}, {})", lo, hi),
+            Self::NonDecreasing => write!(f, "NonDecreasing"),
+            Self::RunAtLeast(n) => write!(f, "RunAtLeast({})", n),
+            Self::RunExactly(n) => write!(f, "RunExactly({})", n),
+            Self::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+pub fn digits(mut n: u64) -> Vec<u8> {
+    let mut digits = vec![(n % 10) as u8];
+    while n >= 10 {
+        n /= 10;
+        digits.push((n % 10) as u8);
+    }
+    digits.reverse();
+    digits
+}
+
+// Lengths of the groups of equal adjacent digits.
+fn runs(digits: &[u8]) -> impl Iterator<Item = usize> + '_ {
+    let mut rest = digits;
+    std::iter::from_fn(move || {
+        let first = *rest.first()?;
+        let len = rest.iter().take_while(|&&d| d == first).count();
+        rest = &rest[len..];
+        Some(len)
+    })
+}
+
+impl Rule {
+    pub fn custom<F: Fn(&[u8]) -> bool + Send + Sync + 'static>(predicate: F) -> Self {
+        Self::Custom(Arc::new(predicate))
+    }
+    pub fn matches(&self, digits: &[u8]) -> bool {
+        match self {
+            Self::Length(n) => digits.len() == *n,
+            Self::DigitRange(lo, hi) => digits.iter().all(|d| lo <= d && d <= hi),
+            Self::NonDecreasing => digits.windows(2).all(|w| w[0] <= w[1]),
+            Self::RunAtLeast(n) => runs(digits).any(|len| len >= *n),
+            Self::RunExactly(n) => runs(digits).any(|len| len == *n),
+            Self::Custom(predicate) => predicate(digits),
+        }
+    }
+}
+
+// The most numbers `RuleSet::count` checks one by one.
+pub const BRUTE_FORCE_LIMIT: u64 = 1_000_000;
+
+impl RuleSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn with(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+    // Six non-decreasing digits with a pair of equal adjacent digits.
+    pub fn day4_part1() -> Self {
+        Self::new()
+            .with(Rule::Length(6))
+            .with(Rule::NonDecreasing)
+            .with(Rule::RunAtLeast(2))
+    }
+    // As part 1, with a pair that is not part of a longer group.
+    pub fn day4_part2() -> Self {
+        Self::new()
+            .with(Rule::Length(6))
+            .with(Rule::NonDecreasing)
+            .with(Rule::RunExactly(2))
+    }
+    pub fn matches(&self, password: u64) -> bool {
+        let digits = digits(password);
+        self.rules.iter().all(|rule| rule.matches(&digits))
+    }
+    // Counts digit by digit when every rule is built in, or only looks at
+    // non-decreasing numbers when that is one of the rules. Saturates at
+    // u64::MAX, which only an unconstrained 0..=u64::MAX can exceed.
+    // Otherwise, with a custom rule or more than 64 distinct run rules, every
+    // number has to be checked: that is done for ranges of up to
+    // `BRUTE_FORCE_LIMIT` numbers, and None returned for larger ones, which
+    // `count_brute_force` still counts if given the time.
+    pub fn count(&self, range: RangeInclusive<u64>) -> Option<u64> {
+        let (start, end) = range.clone().into_inner();
+        let run_bits = self.run_bits();
+        Some(if start > end {
+            0
+        } else if let Some(run_bits) = run_bits.filter(|_| {
+            self.rules
+                .iter()
+                .all(|rule| !matches!(rule, Rule::Custom(_)))
+        }) {
+            let below = match start.checked_sub(1) {
+                Some(n) => self.count_up_to(n, &run_bits),
+                None => 0,
+            };
+            u64::try_from(self.count_up_to(end, &run_bits) - below).unwrap_or(u64::MAX)
+        } else if self
+            .rules
+            .iter()
+            .any(|rule| matches!(rule, Rule::NonDecreasing))
+        {
+            self.count_non_decreasing(range)
+        } else if end - start < BRUTE_FORCE_LIMIT {
+            self.count_brute_force(range)
+        } else {
+            return None;
+        })
+    }
+    pub fn count_brute_force(&self, range: RangeInclusive<u64>) -> u64 {
+        range.filter(|&n| self.matches(n)).count() as u64
+    }
+    // The bit of `State::satisfied` that tracks each run rule, shared by
+    // equal rules, or None if the distinct run rules don't fit in it.
+    fn run_bits(&self) -> Option<Vec<Option<u32>>> {
+        let mut distinct = Vec::new();
+        let mut bits = Vec::with_capacity(self.rules.len());
+        for rule in &self.rules {
+            let key = match *rule {
+                Rule::RunAtLeast(n) => (false, n),
+                Rule::RunExactly(n) => (true, n),
+                _ => {
+                    bits.push(None);
+                    continue;
+                }
+            };
+            let bit = match distinct.iter().position(|&k| k == key) {
+                Some(bit) => bit,
+                None => {
+                    distinct.push(key);
+                    distinct.len() - 1
+                }
+            };
+            bits.push(Some(
+                u32::try_from(bit).ok().filter(|&bit| bit < u64::BITS)?,
+            ));
+        }
+        Some(bits)
+    }
+    // Matches in 0..=end, by a digit DP over the decimal digits of `end`.
+    fn count_up_to(&self, end: u64, run_bits: &[Option<u32>]) -> u128 {
+        let zero = u128::from(self.matches(0));
+        let mut dp = DigitDp {
+            rules: &self.rules,
+            run_bits,
+            bound: digits(end),
+            cap: self
+                .rules
+                .iter()
+                .map(|rule| match rule {
+                    Rule::RunAtLeast(n) | Rule::RunExactly(n) => n + 1,
+                    _ => 1,
+                })
+                .max()
+                .unwrap_or(1),
+            memo: HashMap::new(),
+        };
+        let state = State {
+            pos: 0,
+            len: 0,
+            prev: 0,
+            run: 0,
+            satisfied: 0,
+        };
+        zero + dp.count(state, true)
+    }
+    // Visits every non-decreasing digit sequence whose length fits the range.
+    fn count_non_decreasing(&self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        let mut count = u64::from(start == 0 && self.matches(0));
+        let mut digits = Vec::new();
+        for len in self::digits(start).len()..=self::digits(end).len() {
+            self.visit_non_decreasing(&mut digits, len, 0, &mut |digits, n| {
+                if start <= n && n <= end && self.rules.iter().all(|r| r.matches(digits)) {
+                    count += 1;
+                }
+            });
+        }
+        count
+    }
+    fn visit_non_decreasing<F: FnMut(&[u8], u64)>(
+        &self,
+        digits: &mut Vec<u8>,
+        len: usize,
+        value: u64,
+        visit: &mut F,
+    ) {
+        if digits.len() == len {
+            visit(digits, value);
+            return;
+        }
+        let first = digits.last().copied().unwrap_or(1);
+        for d in first..=9 {
+            let value = match value
+                .checked_mul(10)
+                .and_then(|v| v.checked_add(u64::from(d)))
+            {
+                Some(value) => value,
+                None => break,
+            };
+            digits.push(d);
+            self.visit_non_decreasing(digits, len, value, visit);
+            digits.pop();
+        }
+    }
+}
+
+// Digits placed so far, for numbers above zero; `len` stays 0 while only
+// leading zeros have been placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct State {
+    pos: usize,
+    len: usize,
+    prev: u8,
+    // Length of the current group of equal digits, capped one past the
+    // longest run any rule asks for.
+    run: usize,
+    // Run rules already satisfied by an earlier group, one bit per distinct
+    // rule.
+    satisfied: u64,
+}
+
+struct DigitDp<'a> {
+    rules: &'a [Rule],
+    run_bits: &'a [Option<u32>],
+    bound: Vec<u8>,
+    cap: usize,
+    memo: HashMap<State, u128>,
+}
+
+impl<'a> DigitDp<'a> {
+    // Matches among the numbers that continue `state`, staying at or below
+    // the bound if `tight`.
+    fn count(&mut self, state: State, tight: bool) -> u128 {
+        if state.pos == self.bound.len() {
+            return u128::from(state.len > 0 && self.accepts(state));
+        }
+        if !tight {
+            if let Some(&count) = self.memo.get(&state) {
+                return count;
+            }
+        }
+        let limit = if tight { self.bound[state.pos] } else { 9 };
+        let mut count = 0;
+        for d in 0..=limit {
+            if let Some(next) = self.place(state, d) {
+                count += self.count(next, tight && d == limit);
+            }
+        }
+        if !tight {
+            self.memo.insert(state, count);
+        }
+        count
+    }
+    fn place(&self, state: State, d: u8) -> Option<State> {
+        let mut next = State {
+            pos: state.pos + 1,
+            ..state
+        };
+        if state.len == 0 && d == 0 {
+            return Some(next);
+        }
+        let continues = state.len > 0 && d == state.prev;
+        next.len += 1;
+        next.prev = d;
+        next.run = if continues {
+            (state.run + 1).min(self.cap)
+        } else {
+            1
+        };
+        for (rule, bit) in self.rules.iter().zip(self.run_bits) {
+            let mask = bit.map_or(0, |bit| 1 << bit);
+            match *rule {
+                Rule::DigitRange(lo, hi) if d < lo || d > hi => return None,
+                Rule::NonDecreasing if state.len > 0 && d < state.prev => return None,
+                Rule::Length(n) if next.len > n => return None,
+                Rule::RunAtLeast(n) if next.run >= n => next.satisfied |= mask,
+                Rule::RunExactly(n) if !continues && state.len > 0 && state.run == n => {
+                    next.satisfied |= mask
+                }
+                _ => {}
+            }
+        }
+        Some(next)
+    }
+    fn accepts(&self, state: State) -> bool {
+        self.rules.iter().zip(self.run_bits).all(|(rule, bit)| {
+            let satisfied = bit.is_some_and(|bit| state.satisfied & 1 << bit != 0);
+            match *rule {
+                Rule::Length(n) => state.len == n,
+                Rule::RunAtLeast(_) => satisfied,
+                Rule::RunExactly(n) => satisfied || state.run == n,
+                _ => true,
+            }
+        })
+    }
+}
+
+// A range written as `start-end`, both ends included.
+pub fn parse_range(input: &str) -> Result<RangeInclusive<u64>, String> {
+    let input = input.trim();
+    let bound = |s: &str| {
+        s.trim()
+            .parse::<u64>()
+            .map_err(|_| format!("invalid bound `{}` in range `{}`", s.trim(), input))
+    };
+    match input.split_once('-') {
+        Some((start, end)) => Ok(bound(start)?..=bound(end)?),
+        None => Err(format!("expected `start-end`, got `{}`", input)),
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    #[test]
+    fn test_day4_rules() {
+        let part1 = RuleSet::day4_part1();
+        assert!(part1.matches(111_111));
+        assert!(!part1.matches(223_450));
+        assert!(!part1.matches(123_789));
+        assert!(!part1.matches(11_111));
+        let part2 = RuleSet::day4_part2();
+        assert!(part2.matches(112_233));
+        assert!(!part2.matches(123_444));
+        assert!(part2.matches(111_122));
+        assert_eq!(part1.count(193_651..=649_729), Some(1605));
+        assert_eq!(part2.count(193_651..=649_729), Some(1102));
+    }
+    #[test]
+    fn test_rules() {
+        assert_eq!(digits(0), [0]);
+        assert_eq!(digits(1_230_045), [1, 2, 3, 0, 0, 4, 5]);
+        assert_eq!(runs(&[1, 1, 2, 3, 3, 3]).collect::<Vec<_>>(), [2, 1, 3]);
+        assert!(Rule::DigitRange(2, 7).matches(&[2, 5, 7]));
+        assert!(!Rule::DigitRange(2, 7).matches(&[2, 8, 7]));
+        assert!(Rule::RunAtLeast(3).matches(&[1, 2, 2, 2, 2]));
+        assert!(!Rule::RunExactly(3).matches(&[1, 2, 2, 2, 2]));
+
+        let no_sevens = RuleSet::new()
+            .with(Rule::NonDecreasing)
+            .with(Rule::custom(|digits| !digits.contains(&7)));
+        assert_eq!(no_sevens.count(0..=99), Some(45));
+        assert!(format!("{:?}", no_sevens).contains("[NonDecreasing, Custom(..)]"));
+    }
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range("193651-649729\n"), Ok(193_651..=649_729));
+        assert_eq!(
+            parse_range("12-x"),
+            Err("invalid bound `x` in range `12-x`".to_string())
+        );
+        assert!(parse_range("12").is_err());
+    }
+    #[test]
+    fn test_count_matches_brute_force() {
+        for rules in [RuleSet::day4_part1(), RuleSet::day4_part2()].iter() {
+            assert_eq!(
+                rules.count(193_651..=649_729),
+                Some(rules.count_brute_force(193_651..=649_729))
+            );
+        }
+
+        let mut rng = crate::rng::Rng::new(4);
+        for _ in 0..200 {
+            let mut rules = RuleSet::new();
+            for _ in 0..rng.below(4) {
+                let n = rng.below(4) + 1;
+                rules = rules.with(match rng.below(6) {
+                    0 => Rule::Length(n + 1),
+                    1 => Rule::DigitRange(rng.below(5) as u8, rng.below(5) as u8 + 5),
+                    2 => Rule::NonDecreasing,
+                    3 => Rule::RunAtLeast(n),
+                    4 => Rule::RunExactly(n),
+                    _ => Rule::custom(move |digits| digits.len() % 2 == n % 2),
+                });
+            }
+            let start = rng.range(0, 30_000) as u64;
+            let end = start + rng.range(0, 30_000) as u64;
+            assert_eq!(
+                rules.count(start..=end),
+                Some(rules.count_brute_force(start..=end)),
+                "{:?} {}..={}",
+                rules,
+                start,
+                end
+            );
+        }
+    }
+    #[test]
+    fn test_count_many_run_rules() {
+        // Equal rules share a bit, so these still count digit by digit.
+        let mut repeated = RuleSet::new().with(Rule::Length(5));
+        for _ in 0..100 {
+            repeated = repeated.with(Rule::RunExactly(2)).with(Rule::RunAtLeast(3));
+        }
+        assert!(repeated.run_bits().is_some());
+        assert_eq!(
+            repeated.count(0..=99_999),
+            Some(repeated.count_brute_force(0..=99_999))
+        );
+        assert_eq!(repeated.count(0..=99_999), Some(9 * 9 * 2));
+
+        // Too many distinct ones to track falls back to the other counts.
+        let mut distinct = RuleSet::new();
+        for n in 1..=70 {
+            distinct = distinct
+                .with(Rule::RunAtLeast(n % 4 + 1))
+                .with(Rule::RunExactly(n));
+        }
+        assert!(distinct.run_bits().is_none());
+        assert_eq!(distinct.count(0..=99_999), Some(0));
+        assert_eq!(distinct.count(0..=10u64.pow(12)), None);
+        let distinct = distinct.with(Rule::NonDecreasing);
+        assert_eq!(distinct.count(0..=10u64.pow(12)), Some(0));
+    }
+    #[test]
+    fn test_count_large_ranges() {
+        // Multisets of 18 digits out of 1..=9.
+        let rules = RuleSet::new().with(Rule::NonDecreasing);
+        assert_eq!(
+            rules.count(10u64.pow(17)..=10u64.pow(18) - 1),
+            Some(1_562_275)
+        );
+        assert_eq!(
+            rules.count(0..=10u64.pow(19) - 1),
+            Some((1..=19).map(|len| binomial(len + 8, 8)).sum::<u64>() + 1)
+        );
+        // Eighteen digits out of nine always repeat one.
+        let part1 = RuleSet::new()
+            .with(Rule::NonDecreasing)
+            .with(Rule::RunAtLeast(2));
+        assert_eq!(
+            part1.count(10u64.pow(17)..=10u64.pow(18) - 1),
+            Some(1_562_275)
+        );
+        assert_eq!(RuleSet::new().count(0..=u64::MAX), Some(u64::MAX));
+        let empty = RangeInclusive::new(5, 4);
+        assert_eq!(RuleSet::new().count(empty), Some(0));
+
+        // Twelve digits are few enough to enumerate with a custom rule.
+        let custom = RuleSet::day4_part2()
+            .with(Rule::Length(12))
+            .with(Rule::custom(|_| true));
+        let built_in = RuleSet::day4_part2().with(Rule::Length(12));
+        let range = 123_456_789_012..=987_654_321_098;
+        assert_eq!(custom.count(range.clone()), built_in.count(range));
+
+        // Without non-decreasing digits only small ranges are enumerated.
+        let odd = RuleSet::new().with(Rule::custom(|digits| digits[digits.len() - 1] % 2 == 1));
+        assert_eq!(
+            odd.count(0..=BRUTE_FORCE_LIMIT - 1),
+            Some(BRUTE_FORCE_LIMIT / 2)
+        );
+        assert_eq!(odd.count(0..=BRUTE_FORCE_LIMIT), None);
+        assert_eq!(odd.count(u64::MAX..=u64::MAX), Some(1));
+    }
+
+    fn binomial(n: u64, k: u64) -> u64 {
+        (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+    }
+}
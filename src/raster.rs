@@ -0,0 +1,314 @@
+use crate::sif::{Layer, BLACK, WHITE};
+
+// RGBA colors for the three Space Image Format colors; any other digit is
+// drawn as transparent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    pub black: [u8; 4],
+    pub white: [u8; 4],
+    pub transparent: [u8; 4],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    // Side in output pixels of each image pixel.
+    pub scale: usize,
+    pub palette: Palette,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Pbm,
+    Pgm,
+    Ppm,
+    Png,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self {
+            black: [0, 0, 0, 255],
+            white: [255, 255, 255, 255],
+            transparent: [0, 0, 0, 0],
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            scale: 1,
+            palette: Palette::default(),
+        }
+    }
+}
+
+impl Palette {
+    pub fn color(&self, digit: u8) -> [u8; 4] {
+        match digit {
+            BLACK => self.black,
+            WHITE => self.white,
+            _ => self.transparent,
+        }
+    }
+}
+
+impl Format {
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match ext.to_ascii_lowercase().as_str() {
+            "pbm" => Some(Self::Pbm),
+            "pgm" => Some(Self::Pgm),
+            "ppm" => Some(Self::Ppm),
+            "png" => Some(Self::Png),
+            _ => None,
+        }
+    }
+}
+
+pub fn encode(format: Format, layer: &Layer, options: &Options) -> Vec<u8> {
+    match format {
+        Format::Pbm => pbm(layer, options),
+        Format::Pgm => pgm(layer, options),
+        Format::Ppm => ppm(layer, options),
+        Format::Png => png(layer, options),
+    }
+}
+
+// Scaled rows of RGBA colors.
+fn scaled(layer: &Layer, options: &Options) -> (usize, usize, Vec<[u8; 4]>) {
+    let scale = options.scale.max(1);
+    let (width, height) = (layer.width() * scale, layer.height() * scale);
+    let mut pixels = Vec::with_capacity(width * height);
+    for row in layer.rows() {
+        let colors: Vec<[u8; 4]> = row
+            .iter()
+            .flat_map(|&digit| std::iter::repeat_n(options.palette.color(digit), scale))
+            .collect();
+        for _ in 0..scale {
+            pixels.extend_from_slice(&colors);
+        }
+    }
+    (width, height, pixels)
+}
+
+// Alpha blends over white, then ITU-R BT.601 luma.
+fn gray(color: [u8; 4]) -> u8 {
+    let over_white =
+        |c: u8| (u32::from(c) * u32::from(color[3]) + 255 * (255 - u32::from(color[3]))) / 255;
+    let (r, g, b) = (
+        over_white(color[0]),
+        over_white(color[1]),
+        over_white(color[2]),
+    );
+    ((299 * r + 587 * g + 114 * b + 500) / 1000) as u8
+}
+
+// Binary PBM, with ink wherever the palette color is darker than mid gray.
+pub fn pbm(layer: &Layer, options: &Options) -> Vec<u8> {
+    let (width, height, pixels) = scaled(layer, options);
+    let mut out = format!("P4\n{} {}\n", width, height).into_bytes();
+    for row in pixels.chunks(width.max(1)).take(height) {
+        for byte in row.chunks(8) {
+            let bits = byte
+                .iter()
+                .enumerate()
+                .filter(|(_, &color)| gray(color) < 128)
+                .fold(0u8, |bits, (i, _)| bits | 0x80 >> i);
+            out.push(bits);
+        }
+    }
+    out
+}
+
+pub fn pgm(layer: &Layer, options: &Options) -> Vec<u8> {
+    let (width, height, pixels) = scaled(layer, options);
+    let mut out = format!("P5\n{} {}\n255\n", width, height).into_bytes();
+    out.extend(pixels.iter().map(|&color| gray(color)));
+    out
+}
+
+// Colors are blended over white, as PPM has no alpha channel.
+pub fn ppm(layer: &Layer, options: &Options) -> Vec<u8> {
+    let (width, height, pixels) = scaled(layer, options);
+    let mut out = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+    for color in pixels {
+        let alpha = u32::from(color[3]);
+        for &c in color[..3].iter() {
+            out.push(((u32::from(c) * alpha + 255 * (255 - alpha)) / 255) as u8);
+        }
+    }
+    out
+}
+
+// 8-bit RGBA PNG. The image data is zlib-wrapped but stored uncompressed,
+// which keeps the encoder tiny and is fine for images this small.
+pub fn png(layer: &Layer, options: &Options) -> Vec<u8> {
+    let (width, height, pixels) = scaled(layer, options);
+    // Each scanline starts with filter type 0.
+    let mut raw = Vec::with_capacity(height * (1 + 4 * width));
+    for row in pixels.chunks(width.max(1)).take(height) {
+        raw.push(0);
+        for color in row {
+            raw.extend_from_slice(color);
+        }
+    }
+
+    let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+    let mut header = Vec::new();
+    header.extend_from_slice(&(width as u32).to_be_bytes());
+    header.extend_from_slice(&(height as u32).to_be_bytes());
+    header.extend_from_slice(&[8, 6, 0, 0, 0]);
+    chunk(&mut out, b"IHDR", &header);
+    chunk(&mut out, b"IDAT", &zlib_stored(&raw));
+    chunk(&mut out, b"IEND", &[]);
+    out
+}
+
+fn chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+    let crc = crc32(&out[start..]);
+    out.extend_from_slice(&crc.to_be_bytes());
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                0xedb8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + u32::from(byte)) % 65_521;
+        b = (b + a) % 65_521;
+    }
+    b << 16 | a
+}
+
+// A zlib stream of stored deflate blocks of at most 65535 bytes.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(0xffff).peekable();
+    if blocks.peek().is_none() {
+        out.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        out.push(if blocks.peek().is_none() { 1 } else { 0 });
+        let len = block.len() as u16;
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(!len).to_le_bytes());
+        out.extend_from_slice(block);
+    }
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::sif::TRANSPARENT;
+
+    fn layer() -> Layer {
+        Layer::new(3, 2, vec![0, 1, 2, 1, 0, 1]).unwrap()
+    }
+    // Reads back what `png` writes: its chunks and the stored image data.
+    fn decode_png(png: &[u8]) -> (Vec<[u8; 4]>, Vec<u8>) {
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        let mut chunks = Vec::new();
+        let mut pos = 8;
+        while pos < png.len() {
+            let len =
+                u32::from_be_bytes([png[pos], png[pos + 1], png[pos + 2], png[pos + 3]]) as usize;
+            let body = &png[pos + 4..pos + 8 + len];
+            let crc = &png[pos + 8 + len..pos + 12 + len];
+            assert_eq!(crc32(body).to_be_bytes(), crc);
+            chunks.push(body.to_vec());
+            pos += 12 + len;
+        }
+        let kinds: Vec<[u8; 4]> = chunks.iter().map(|c| [c[0], c[1], c[2], c[3]]).collect();
+        let zlib = &chunks[1][4..];
+        let mut raw = Vec::new();
+        let mut pos = 2;
+        loop {
+            let last = zlib[pos] & 1 == 1;
+            let len = u16::from_le_bytes([zlib[pos + 1], zlib[pos + 2]]) as usize;
+            raw.extend_from_slice(&zlib[pos + 5..pos + 5 + len]);
+            pos += 5 + len;
+            if last {
+                break;
+            }
+        }
+        assert_eq!(&zlib[pos..], adler32(&raw).to_be_bytes());
+        (kinds, raw)
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+    #[test]
+    fn test_netpbm() {
+        let options = Options::default();
+        assert_eq!(pbm(&layer(), &options), b"P4\n3 2\n\x80\x40");
+        assert_eq!(
+            pgm(&layer(), &options),
+            b"P5\n3 2\n255\n\x00\xff\xff\xff\x00\xff"
+        );
+        let red = Options {
+            scale: 2,
+            palette: Palette {
+                transparent: [255, 0, 0, 255],
+                ..Palette::default()
+            },
+        };
+        let ppm = ppm(&layer(), &red);
+        assert!(ppm.starts_with(b"P6\n6 4\n255\n"));
+        let body = &ppm[b"P6\n6 4\n255\n".len()..];
+        assert_eq!(body.len(), 6 * 4 * 3);
+        assert_eq!(&body[12..18], [255, 0, 0, 255, 0, 0]);
+        assert_eq!(&body[18 * 3..18 * 3 + 6], [255, 255, 255, 255, 255, 255]);
+        // Nine pixels wide takes two bytes a row.
+        let wide = Layer::new(9, 1, vec![0, 1, 0, 1, 0, 1, 0, 1, 0]).unwrap();
+        assert_eq!(pbm(&wide, &options), b"P4\n9 1\n\xaa\x80");
+    }
+    #[test]
+    fn test_png() {
+        let options = Options {
+            scale: 2,
+            ..Options::default()
+        };
+        let png = png(&layer(), &options);
+        let (kinds, raw) = decode_png(&png);
+        assert_eq!(kinds, [*b"IHDR", *b"IDAT", *b"IEND"]);
+        assert_eq!(&png[16..29], [0, 0, 0, 6, 0, 0, 0, 4, 8, 6, 0, 0, 0]);
+        assert_eq!(raw.len(), 4 * (1 + 6 * 4));
+        assert_eq!(&raw[..9], [0, 0, 0, 0, 255, 0, 0, 0, 255]);
+        assert_eq!(&raw[17..25], [0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(encode(Format::Png, &layer(), &options), png);
+
+        // Spans several stored blocks.
+        let large = Layer::filled(200, 100, WHITE).unwrap();
+        let (_, raw) = decode_png(&super::png(&large, &Options::default()));
+        assert_eq!(raw.len(), 100 * (1 + 200 * 4));
+        assert!(raw
+            .chunks(801)
+            .all(|row| row[0] == 0 && row[1..].iter().all(|&b| b == 255)));
+        assert_eq!(Format::from_extension("PNG"), Some(Format::Png));
+        assert_eq!(Format::from_extension("gif"), None);
+        assert_eq!(gray(Palette::default().color(TRANSPARENT)), 255);
+    }
+}
@@ -0,0 +1,300 @@
+use crate::intcode::{IntcodeComputer, Step};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Io {
+    Input(i64),
+    Output(i64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IoEvent {
+    // Number of instructions executed before the one doing the I/O.
+    pub step: usize,
+    pub io: Io,
+}
+
+// Every value a machine consumed and produced since its program was loaded.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Recording {
+    pub events: Vec<IoEvent>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayError {
+    // The machine did `actual` where the recording has `expected`.
+    Mismatch {
+        expected: Option<IoEvent>,
+        actual: IoEvent,
+    },
+    // The machine halted or blocked on input with events left to replay.
+    Incomplete {
+        remaining: usize,
+        halted: bool,
+    },
+}
+
+impl fmt::Display for IoEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.io {
+            Io::Input(value) => write!(f, "{} in {}", self.step, value),
+            Io::Output(value) => write!(f, "{} out {}", self.step, value),
+        }
+    }
+}
+
+impl fmt::Display for Recording {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for event in self.events.iter() {
+            writeln!(f, "{}", event)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Mismatch {
+                expected: Some(expected),
+                actual,
+            } => write!(f, "expected `{}`, got `{}`", expected, actual),
+            Self::Mismatch {
+                expected: None,
+                actual,
+            } => write!(f, "unexpected `{}` after the end of the recording", actual),
+            Self::Incomplete { remaining, halted } => write!(
+                f,
+                "machine {} with {} events left to replay",
+                if *halted { "halted" } else { "waits for input" },
+                remaining
+            ),
+        }
+    }
+}
+
+// One event per line, e.g. `12 in 5` or `40 out -3`.
+impl FromStr for Recording {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut events = Vec::new();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let event = match fields.as_slice() {
+                [step, kind, value] => {
+                    let step = step.parse::<usize>().ok();
+                    let value = value.parse::<i64>().ok();
+                    match (step, *kind, value) {
+                        (Some(step), "in", Some(value)) => Some(IoEvent {
+                            step,
+                            io: Io::Input(value),
+                        }),
+                        (Some(step), "out", Some(value)) => Some(IoEvent {
+                            step,
+                            io: Io::Output(value),
+                        }),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            };
+            match event {
+                Some(event) => events.push(event),
+                None => return Err(format!("line {}: invalid event `{}`", i + 1, line)),
+            }
+        }
+        Ok(Self { events })
+    }
+}
+
+impl Recording {
+    pub fn inputs(&self) -> Vec<i64> {
+        self.events
+            .iter()
+            .filter_map(|event| match event.io {
+                Io::Input(value) => Some(value),
+                _ => None,
+            })
+            .collect()
+    }
+    pub fn outputs(&self) -> Vec<i64> {
+        self.events
+            .iter()
+            .filter_map(|event| match event.io {
+                Io::Output(value) => Some(value),
+                _ => None,
+            })
+            .collect()
+    }
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        fs::read_to_string(path)?
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+    // Feeds the recorded inputs to a machine in the state the recording
+    // started from (usually freshly loaded), checking that every input is
+    // consumed and every output produced at the recorded step.
+    pub fn replay(&self, computer: &mut IntcodeComputer) -> Result<(), ReplayError> {
+        let mut events = self.events.iter().peekable();
+        loop {
+            let expected = events.peek().copied().copied();
+            let input = match expected {
+                Some(IoEvent {
+                    io: Io::Input(value),
+                    ..
+                }) => Some(value),
+                _ => None,
+            };
+            let steps = computer.steps();
+            let io = match computer.step(input) {
+                Step::Continue => continue,
+                Step::Input => Io::Input(input.unwrap()),
+                Step::Output(value) => Io::Output(value),
+                Step::WaitingForInput | Step::Halted if expected.is_none() => return Ok(()),
+                step => {
+                    return Err(ReplayError::Incomplete {
+                        remaining: events.count(),
+                        halted: step == Step::Halted,
+                    })
+                }
+            };
+            let actual = IoEvent { step: steps, io };
+            if expected != Some(actual) {
+                return Err(ReplayError::Mismatch { expected, actual });
+            }
+            events.next();
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use std::env;
+
+    const FEEDBACK_LOOP: [i64; 29] = [
+        3, 26, 1001, 26, -4, 26, 3, 27, 1002, 27, 2, 27, 1, 27, 26, 27, 4, 27, 1001, 28, -1, 28,
+        1005, 28, 6, 99, 0, 0, 5,
+    ];
+
+    // The day 7 feedback loop, with every amplifier recording its I/O.
+    fn feedback_session(program: &[i64], phases: &[i64]) -> (i64, Vec<Recording>) {
+        let mut amps: Vec<IntcodeComputer> = phases
+            .iter()
+            .map(|phase| {
+                let mut amp = IntcodeComputer::new();
+                amp.start_recording();
+                amp.load_program(program);
+                amp.execute(&[*phase]);
+                amp
+            })
+            .collect();
+        let mut signal = 0;
+        while !amps[amps.len() - 1].done {
+            for amp in amps.iter_mut() {
+                signal = *amp.execute(&[signal]).last().unwrap();
+            }
+        }
+        let recordings = amps
+            .iter_mut()
+            .map(|amp| amp.stop_recording().unwrap())
+            .collect();
+        (signal, recordings)
+    }
+    #[test]
+    fn test_record_and_replay() {
+        let (signal, recordings) = feedback_session(&FEEDBACK_LOOP, &[9, 8, 7, 6, 5]);
+        assert_eq!(signal, 139_629_729);
+        assert_eq!(recordings[4].outputs().last(), Some(&signal));
+        assert_eq!(recordings[0].inputs()[..2], [9, 0]);
+        assert_eq!(
+            recordings[0].events[0],
+            IoEvent {
+                step: 0,
+                io: Io::Input(9)
+            }
+        );
+
+        for (i, recording) in recordings.iter().enumerate() {
+            let path = env::temp_dir().join(format!(
+                "adventofcode2019-replay-{}-{}.txt",
+                std::process::id(),
+                i
+            ));
+            recording.save(&path).unwrap();
+            let loaded = Recording::load(&path).unwrap();
+            std::fs::remove_file(&path).unwrap();
+            assert_eq!(&loaded, recording);
+
+            let mut computer = IntcodeComputer::new();
+            computer.load_program(&FEEDBACK_LOOP);
+            assert_eq!(loaded.replay(&mut computer), Ok(()));
+            assert!(computer.done);
+        }
+    }
+    #[test]
+    fn test_replay_detects_regressions() {
+        let (_, recordings) = feedback_session(&FEEDBACK_LOOP, &[9, 8, 7, 6, 5]);
+        let mut patched = FEEDBACK_LOOP;
+        patched[10] = 3;
+        let mut computer = IntcodeComputer::new();
+        computer.load_program(&patched);
+        assert_eq!(
+            recordings[0].replay(&mut computer),
+            Err(ReplayError::Mismatch {
+                expected: Some(IoEvent {
+                    step: 11,
+                    io: Io::Output(263)
+                }),
+                actual: IoEvent {
+                    step: 11,
+                    io: Io::Output(392)
+                },
+            })
+        );
+
+        let mut truncated = FEEDBACK_LOOP;
+        truncated[25] = 99;
+        truncated[22] = 1106;
+        computer.load_program(&truncated);
+        let err = recordings[0].replay(&mut computer).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "machine halted with 8 events left to replay"
+        );
+    }
+    #[test]
+    fn test_recording_follows_rewind() {
+        let mut computer = IntcodeComputer::new();
+        computer.enable_journal();
+        computer.start_recording();
+        computer.load_program(&[3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8]);
+        computer.execute(&[8]);
+        assert_eq!(
+            computer.recording().unwrap().to_string(),
+            "0 in 8\n2 out 1\n"
+        );
+        computer.rewind_to(1);
+        assert_eq!(computer.recording().unwrap().to_string(), "0 in 8\n");
+        assert_eq!(computer.steps(), 1);
+        assert_eq!(
+            "0 in 8\n\n2 out x".parse::<Recording>(),
+            Err("line 3: invalid event `2 out x`".to_string())
+        );
+    }
+}
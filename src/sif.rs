@@ -0,0 +1,429 @@
+use crate::rng::Rng;
+use std::fmt;
+
+// Space Image Format pixel colors.
+pub const BLACK: u8 = 0;
+pub const WHITE: u8 = 1;
+pub const TRANSPARENT: u8 = 2;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SifError {
+    EmptySize,
+    // A width × height that overflows usize.
+    TooLarge {
+        width: usize,
+        height: usize,
+    },
+    // The data does not split into whole layers of `layer_size` digits.
+    Length {
+        len: usize,
+        layer_size: usize,
+    },
+    // A character other than a digit, at a 0-based position of the data.
+    InvalidDigit {
+        pos: usize,
+        c: char,
+    },
+    // Pixels given for a layer that do not fill exactly width × height.
+    Size {
+        width: usize,
+        height: usize,
+        len: usize,
+    },
+    // A pixel above 9, at a 0-based position of the layer.
+    InvalidColor {
+        pos: usize,
+        color: u8,
+    },
+    NoLayers,
+    // A layer, by 0-based position, of another size than the first.
+    LayerSize {
+        layer: usize,
+    },
+}
+
+// One width × height grid of digits, row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layer {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+// Layers are listed front to back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    layers: Vec<Layer>,
+}
+
+impl fmt::Display for SifError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::EmptySize => write!(f, "image width and height must be positive"),
+            Self::TooLarge { width, height } => {
+                write!(f, "a {}×{} layer is too large", width, height)
+            }
+            Self::Length { len, layer_size } => write!(
+                f,
+                "{} digits do not make whole layers of {} pixels",
+                len, layer_size
+            ),
+            Self::InvalidDigit { pos, c } => {
+                write!(f, "invalid digit {:?} at position {}", c, pos)
+            }
+            Self::Size { width, height, len } => {
+                write!(f, "{} pixels do not fill a {}×{} layer", len, width, height)
+            }
+            Self::InvalidColor { pos, color } => {
+                write!(f, "invalid color {} at position {}", color, pos)
+            }
+            Self::NoLayers => write!(f, "an image needs a layer"),
+            Self::LayerSize { layer } => {
+                write!(f, "layer {} differs in size from the first", layer)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SifError {}
+
+// The digits of every layer, front to back, as `Image::parse` reads them.
+impl fmt::Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for layer in self.layers.iter() {
+            for &digit in layer.pixels.iter() {
+                write!(f, "{}", digit)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Layer {
+    pub fn new(width: usize, height: usize, pixels: Vec<u8>) -> Result<Self, SifError> {
+        if width == 0 || height == 0 {
+            return Err(SifError::EmptySize);
+        }
+        if width.checked_mul(height) != Some(pixels.len()) {
+            return Err(SifError::Size {
+                width,
+                height,
+                len: pixels.len(),
+            });
+        }
+        if let Some(pos) = pixels.iter().position(|&color| color > 9) {
+            return Err(SifError::InvalidColor {
+                pos,
+                color: pixels[pos],
+            });
+        }
+        Ok(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+    pub fn filled(width: usize, height: usize, color: u8) -> Result<Self, SifError> {
+        let len = width
+            .checked_mul(height)
+            .ok_or(SifError::TooLarge { width, height })?;
+        Self::new(width, height, vec![color; len])
+    }
+    // A fully transparent layer the size of `self`.
+    fn transparent(&self) -> Self {
+        Self {
+            pixels: vec![TRANSPARENT; self.pixels.len()],
+            ..*self
+        }
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+    pub fn get(&self, x: usize, y: usize) -> u8 {
+        self.pixels[y * self.width + x]
+    }
+    pub fn rows(&self) -> impl Iterator<Item = &[u8]> {
+        self.pixels.chunks(self.width)
+    }
+    // How many pixels hold each digit, which every pixel is as `new` checks.
+    pub fn histogram(&self) -> [u64; 10] {
+        let mut histogram = [0; 10];
+        for &digit in self.pixels.iter() {
+            histogram[usize::from(digit)] += 1;
+        }
+        histogram
+    }
+    pub fn count(&self, digit: u8) -> u64 {
+        self.pixels.iter().filter(|&&d| d == digit).count() as u64
+    }
+}
+
+impl Image {
+    // Digits of `width` × `height` layers; surrounding whitespace is ignored.
+    pub fn parse(data: &str, width: usize, height: usize) -> Result<Self, SifError> {
+        let layer_size = width
+            .checked_mul(height)
+            .ok_or(SifError::TooLarge { width, height })?;
+        if layer_size == 0 {
+            return Err(SifError::EmptySize);
+        }
+        let data = data.trim();
+        let digits = data
+            .chars()
+            .enumerate()
+            .map(|(pos, c)| match c.to_digit(10) {
+                Some(d) => Ok(d as u8),
+                None => Err(SifError::InvalidDigit { pos, c }),
+            })
+            .collect::<Result<Vec<u8>, _>>()?;
+        if digits.is_empty() || digits.len() % layer_size != 0 {
+            return Err(SifError::Length {
+                len: digits.len(),
+                layer_size,
+            });
+        }
+        let layers = digits
+            .chunks(layer_size)
+            .map(|pixels| Layer::new(width, height, pixels.to_vec()))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            width,
+            height,
+            layers,
+        })
+    }
+    pub fn from_layers(layers: Vec<Layer>) -> Result<Self, SifError> {
+        let (width, height) = match layers.first() {
+            Some(layer) => (layer.width, layer.height),
+            None => return Err(SifError::NoLayers),
+        };
+        if let Some(layer) = layers
+            .iter()
+            .position(|l| l.width != width || l.height != height)
+        {
+            return Err(SifError::LayerSize { layer });
+        }
+        Ok(Self {
+            width,
+            height,
+            layers,
+        })
+    }
+    // `bitmap` in front of fully transparent layers.
+    pub fn from_bitmap(bitmap: &Layer, layers: usize) -> Self {
+        let mut stack = vec![bitmap.clone()];
+        stack.resize(layers.max(1), bitmap.transparent());
+        Self {
+            width: bitmap.width,
+            height: bitmap.height,
+            layers: stack,
+        }
+    }
+    // Shows each visible pixel of `bitmap` on a random layer, transparent
+    // in front of it and random colors behind it, so that the image still
+    // flattens to `bitmap`.
+    pub fn from_bitmap_with_overlays(bitmap: &Layer, layers: usize, rng: &mut Rng) -> Self {
+        let layers = layers.max(1);
+        let mut stack = vec![bitmap.transparent(); layers];
+        for (i, &color) in bitmap.pixels.iter().enumerate() {
+            if color == TRANSPARENT {
+                continue;
+            }
+            let shown = rng.below(layers);
+            stack[shown].pixels[i] = color;
+            for layer in stack[shown + 1..].iter_mut() {
+                layer.pixels[i] = rng.below(3) as u8;
+            }
+        }
+        Self {
+            width: bitmap.width,
+            height: bitmap.height,
+            layers: stack,
+        }
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn layers(&self) -> &[Layer] {
+        &self.layers
+    }
+    // The layer with the fewest `fewest` digits (the first one on a tie).
+    pub fn layer_with_fewest(&self, fewest: u8) -> &Layer {
+        self.layers
+            .iter()
+            .min_by_key(|layer| layer.count(fewest))
+            .unwrap()
+    }
+    // Day 8 part 1 is `checksum(0, 1, 2)`: on the layer with the fewest
+    // `fewest` digits, the count of `a` digits times the count of `b`s.
+    // None if one of them is not a digit.
+    pub fn checksum(&self, fewest: u8, a: u8, b: u8) -> Option<u128> {
+        if fewest > 9 || a > 9 || b > 9 {
+            return None;
+        }
+        let histogram = self.layer_with_fewest(fewest).histogram();
+        Some(u128::from(histogram[usize::from(a)]) * u128::from(histogram[usize::from(b)]))
+    }
+    // Each pixel takes the color of the front-most layer not transparent
+    // there, staying transparent if every layer is.
+    pub fn flatten(&self) -> Layer {
+        let mut image = self.layers[0].transparent();
+        for layer in self.layers.iter() {
+            for (pixel, &color) in image.pixels.iter_mut().zip(layer.pixels.iter()) {
+                if *pixel == TRANSPARENT {
+                    *pixel = color;
+                }
+            }
+        }
+        image
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    #[test]
+    fn test_parse() {
+        let image = Image::parse("123456789012\n", 3, 2).unwrap();
+        assert_eq!(image.layers().len(), 2);
+        assert_eq!(image.layers()[1].pixels(), [7, 8, 9, 0, 1, 2]);
+        assert_eq!(image.layers()[0].get(2, 1), 6);
+        let rows: Vec<&[u8]> = image.layers()[0].rows().collect();
+        assert_eq!(rows, [[1, 2, 3], [4, 5, 6]]);
+
+        assert_eq!(
+            Image::parse("1234567890", 3, 2),
+            Err(SifError::Length {
+                len: 10,
+                layer_size: 6
+            })
+        );
+        assert_eq!(
+            Image::parse("", 3, 2).unwrap_err().to_string(),
+            "0 digits do not make whole layers of 6 pixels"
+        );
+        assert_eq!(
+            Image::parse("12345x", 3, 2),
+            Err(SifError::InvalidDigit { pos: 5, c: 'x' })
+        );
+        assert_eq!(Image::parse("123", 0, 2), Err(SifError::EmptySize));
+        assert_eq!(
+            Image::parse("123", usize::MAX, 2),
+            Err(SifError::TooLarge {
+                width: usize::MAX,
+                height: 2
+            })
+        );
+    }
+    #[test]
+    fn test_layers() {
+        assert_eq!(Layer::new(0, 2, vec![]), Err(SifError::EmptySize));
+        assert_eq!(Layer::filled(3, 0, BLACK), Err(SifError::EmptySize));
+        assert_eq!(
+            Layer::new(3, 2, vec![0; 5]),
+            Err(SifError::Size {
+                width: 3,
+                height: 2,
+                len: 5
+            })
+        );
+        assert_eq!(
+            Layer::new(2, 1, vec![0, 10]).unwrap_err().to_string(),
+            "invalid color 10 at position 1"
+        );
+        assert_eq!(
+            Layer::filled(usize::MAX, 2, BLACK),
+            Err(SifError::TooLarge {
+                width: usize::MAX,
+                height: 2
+            })
+        );
+
+        let (front, back) = (
+            Layer::filled(2, 1, WHITE).unwrap(),
+            Layer::filled(2, 1, BLACK).unwrap(),
+        );
+        let image = Image::from_layers(vec![front.clone(), back]).unwrap();
+        assert_eq!(image.to_string(), "1100");
+        assert_eq!(Image::from_layers(vec![]), Err(SifError::NoLayers));
+        assert_eq!(
+            Image::from_layers(vec![
+                front.clone(),
+                front,
+                Layer::filled(1, 2, BLACK).unwrap()
+            ]),
+            Err(SifError::LayerSize { layer: 2 })
+        );
+    }
+    #[test]
+    fn test_flatten() {
+        let image = Image::parse("0222112222120000", 2, 2).unwrap();
+        assert_eq!(image.flatten().pixels(), [0, 1, 1, 0]);
+        let image = Image::parse("2212", 2, 1).unwrap();
+        assert_eq!(image.flatten().pixels(), [1, 2]);
+    }
+    #[test]
+    fn test_statistics() {
+        let image = Image::parse("123456789012", 3, 2).unwrap();
+        assert_eq!(
+            image.layers()[0].histogram(),
+            [0, 1, 1, 1, 1, 1, 1, 0, 0, 0]
+        );
+        assert_eq!(image.layers()[1].count(9), 1);
+        assert_eq!(image.layer_with_fewest(0), &image.layers()[0]);
+        assert_eq!(image.checksum(0, 1, 2), Some(1));
+        assert_eq!(image.checksum(3, 7, 0), Some(1));
+        assert_eq!(image.checksum(9, 9, 9), Some(0));
+        assert_eq!(image.checksum(0, 1, 10), None);
+        assert_eq!(image.checksum(255, 1, 2), None);
+
+        // Counts that would overflow the u8 counters day 8 used to keep.
+        let (width, height) = (100, 100);
+        let layer = |ones| "1".repeat(ones) + &"2".repeat(width * height - ones);
+        let data = layer(9000) + &layer(300) + &layer(5000);
+        let image = Image::parse(&data, width, height).unwrap();
+        assert_eq!(image.layers()[1].histogram()[2], 9700);
+        assert_eq!(image.checksum(1, 1, 2), Some(300 * 9700));
+        assert_eq!(image.checksum(0, 1, 2), Some(9000 * 1000));
+    }
+    #[test]
+    fn test_from_bitmap() {
+        let bitmap = Layer::new(3, 2, vec![0, 1, 2, 1, 2, 0]).unwrap();
+        let image = Image::from_bitmap(&bitmap, 2);
+        assert_eq!(image.to_string(), "012120222222");
+        assert_eq!(Image::parse(&image.to_string(), 3, 2), Ok(image.clone()));
+        assert_eq!(image.flatten(), bitmap);
+
+        let mut rng = Rng::new(8);
+        for layers in 1..50 {
+            let image = Image::from_bitmap_with_overlays(&bitmap, layers, &mut rng);
+            let decoded = Image::parse(&image.to_string(), 3, 2).unwrap();
+            assert_eq!(decoded.layers().len(), layers);
+            assert_eq!(decoded.flatten(), bitmap);
+        }
+        let image = Image::from_bitmap_with_overlays(&bitmap, 100, &mut rng);
+        assert!(image.layers()[0].count(TRANSPARENT) > 2);
+        assert!(image.layers()[99].count(TRANSPARENT) < 6);
+    }
+    #[test]
+    fn test_ocr_fixtures() {
+        let mut rng = Rng::new(9);
+        for text in ["FPUAR", "HELLO", "ZJKCGBS"].iter() {
+            let bitmap = crate::ocr::draw(text).unwrap();
+            let image = Image::from_bitmap_with_overlays(&bitmap, 20, &mut rng);
+            let decoded = Image::parse(&image.to_string(), bitmap.width(), 6).unwrap();
+            assert_eq!(crate::ocr::ocr(&decoded.flatten()).as_deref(), Ok(*text));
+        }
+    }
+}
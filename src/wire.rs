@@ -0,0 +1,972 @@
+use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
+use std::fmt;
+
+pub type Point = (i64, i64);
+
+// One straight run of a wire; `steps` is the wire length walked before `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment {
+    pub start: Point,
+    pub end: Point,
+    pub steps: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Wire {
+    pub segments: Vec<Segment>,
+}
+
+// Steps a wire needs to reach the start of a crossing for the first time.
+// Along a run they change by one per point towards its end, up if `rising`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Steps {
+    pub at_start: u64,
+    pub rising: bool,
+}
+
+// Where wires meet: a single point, or a run of points along a row or column
+// where collinear segments overlap, from `start` to `end` (lowest coordinate
+// first). The same wires pass through every point of a run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Crossing {
+    pub start: Point,
+    pub end: Point,
+    // The steps of each wire through the crossing, by wire index.
+    pub steps: BTreeMap<usize, Steps>,
+}
+
+// A run along one row or column, by coordinate along it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Piece {
+    lo: i64,
+    hi: i64,
+    steps: BTreeMap<usize, Steps>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub direction: Direction,
+    pub len: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    Empty,
+    UnknownDirection(char),
+    InvalidLength,
+    // The wire leaves the range of i64 coordinates.
+    OutOfRange,
+}
+
+// A bad move, located by 1-based line and column of its first character.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub kind: ParseErrorKind,
+}
+
+impl Direction {
+    pub fn delta(self) -> (i64, i64) {
+        match self {
+            Self::Up => (0, 1),
+            Self::Down => (0, -1),
+            Self::Left => (-1, 0),
+            Self::Right => (1, 0),
+        }
+    }
+}
+
+impl ParseError {
+    fn new(column: usize, token: &str, kind: ParseErrorKind) -> Self {
+        Self {
+            line: 1,
+            column,
+            token: token.to_string(),
+            kind,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match self.kind {
+            ParseErrorKind::Empty => write!(f, "missing move"),
+            ParseErrorKind::UnknownDirection(c) => {
+                write!(f, "unknown direction `{}` in `{}`", c, self.token)
+            }
+            ParseErrorKind::InvalidLength => write!(f, "invalid length in `{}`", self.token),
+            ParseErrorKind::OutOfRange => write!(f, "`{}` goes out of range", self.token),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Each move of a path with its column and trimmed text.
+fn moves(path: &str) -> impl Iterator<Item = (usize, &str, Result<Move, ParseError>)> {
+    let blank = path.trim().is_empty();
+    let mut offset = 0;
+    path.split(',').filter(move |_| !blank).map(move |raw| {
+        let start = offset + raw.len() - raw.trim_start().len();
+        offset += raw.len() + 1;
+        let column = path[..start].chars().count() + 1;
+        let token = raw.trim();
+        (
+            column,
+            token,
+            parse_move(token).map_err(|kind| ParseError::new(column, token, kind)),
+        )
+    })
+}
+
+fn parse_move(token: &str) -> Result<Move, ParseErrorKind> {
+    let mut chars = token.chars();
+    let direction = match chars.next() {
+        None => return Err(ParseErrorKind::Empty),
+        Some(c) => match c.to_ascii_uppercase() {
+            'U' => Direction::Up,
+            'D' => Direction::Down,
+            'L' => Direction::Left,
+            'R' => Direction::Right,
+            _ => return Err(ParseErrorKind::UnknownDirection(c)),
+        },
+    };
+    let len = chars.as_str().trim_start();
+    if !len.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseErrorKind::InvalidLength);
+    }
+    // No move can be longer than the range of coordinates on its own.
+    match len.parse::<u64>() {
+        Ok(len) if i64::try_from(len).is_ok() => Ok(Move { direction, len }),
+        Err(_) if len.is_empty() => Err(ParseErrorKind::InvalidLength),
+        _ => Err(ParseErrorKind::OutOfRange),
+    }
+}
+
+// Comma separated moves, case insensitive and ignoring whitespace around them.
+pub fn parse_moves(path: &str) -> Result<Vec<Move>, ParseError> {
+    moves(path).map(|(_, _, m)| m).collect()
+}
+
+impl Steps {
+    // The steps `offset` points past the start.
+    pub fn at(self, offset: u64) -> u64 {
+        if self.rising {
+            self.at_start + offset
+        } else {
+            self.at_start - offset
+        }
+    }
+}
+
+impl Crossing {
+    fn point(point: Point, steps: BTreeMap<usize, u64>) -> Self {
+        let steps = steps
+            .into_iter()
+            .map(|(wire, at_start)| {
+                let rising = true;
+                (wire, Steps { at_start, rising })
+            })
+            .collect();
+        Self {
+            start: point,
+            end: point,
+            steps,
+        }
+    }
+    pub fn is_point(&self) -> bool {
+        self.start == self.end
+    }
+    // Number of points in the crossing.
+    pub fn size(&self) -> u64 {
+        distance(self.start, self.end).saturating_add(1)
+    }
+    pub fn contains(&self, point: Point) -> bool {
+        (self.start.0..=self.end.0).contains(&point.0)
+            && (self.start.1..=self.end.1).contains(&point.1)
+    }
+    // Every point of the crossing, from start to end.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (start, end) = (self.start, self.end);
+        let dir = (i64::from(end.0 > start.0), i64::from(end.1 > start.1));
+        (0..=distance(start, end)).map(move |k| {
+            let k = k as i64;
+            (
+                start.0.wrapping_add(dir.0.wrapping_mul(k)),
+                start.1.wrapping_add(dir.1.wrapping_mul(k)),
+            )
+        })
+    }
+    // The steps of each wire at `point`, if it is part of the crossing.
+    pub fn steps_at(&self, point: Point) -> Option<BTreeMap<usize, u64>> {
+        if !self.contains(point) {
+            return None;
+        }
+        let offset = distance(self.start, point);
+        Some(
+            self.steps
+                .iter()
+                .map(|(&wire, steps)| (wire, steps.at(offset)))
+                .collect(),
+        )
+    }
+    pub fn wires(&self) -> Vec<usize> {
+        self.steps.keys().copied().collect()
+    }
+    pub fn has_wire(&self, wire: usize) -> bool {
+        self.steps.contains_key(&wire)
+    }
+    // The point of the crossing closest to the origin.
+    pub fn nearest(&self) -> Point {
+        (
+            0.clamp(self.start.0, self.end.0),
+            0.clamp(self.start.1, self.end.1),
+        )
+    }
+    pub fn distance(&self) -> u64 {
+        distance(self.nearest(), (0, 0))
+    }
+    // The end of the crossing where the wires need the fewest steps in total,
+    // which is the fewest over the whole run as each wire's steps change
+    // steadily along it; ties go to the start.
+    pub fn quickest(&self) -> Point {
+        let end = distance(self.start, self.end);
+        let total = |offset| -> u64 { self.steps.values().map(|s| s.at(offset)).sum() };
+        if total(end) < total(0) {
+            self.end
+        } else {
+            self.start
+        }
+    }
+    // Sum of the steps of every wire at `quickest`.
+    pub fn combined_steps(&self) -> u64 {
+        let offset = distance(self.start, self.quickest());
+        self.steps.values().map(|s| s.at(offset)).sum()
+    }
+}
+
+impl Piece {
+    // Single points have no direction, so their steps are all `rising`.
+    fn new(lo: i64, hi: i64, mut steps: BTreeMap<usize, Steps>) -> Self {
+        if lo == hi {
+            steps.values_mut().for_each(|s| s.rising = true);
+        }
+        Self { lo, hi, steps }
+    }
+    fn len(&self) -> u64 {
+        offset(self.lo, self.hi)
+    }
+    fn steps_at(&self, along: i64) -> BTreeMap<usize, u64> {
+        let offset = offset(self.lo, along);
+        self.steps
+            .iter()
+            .map(|(&wire, steps)| (wire, steps.at(offset)))
+            .collect()
+    }
+    fn slice(&self, lo: i64, hi: i64) -> Self {
+        let offset = offset(self.lo, lo);
+        let steps = self
+            .steps
+            .iter()
+            .map(|(&wire, s)| {
+                let at_start = s.at(offset);
+                (wire, Steps { at_start, ..*s })
+            })
+            .collect();
+        Self::new(lo, hi, steps)
+    }
+    // `self` and `next` as one piece, if `next` carries straight on: it
+    // starts right after, with the same wires whose steps keep changing the
+    // same way.
+    fn join(&self, next: &Self) -> Option<Self> {
+        if self.hi.checked_add(1) != Some(next.lo) || !self.steps.keys().eq(next.steps.keys()) {
+            return None;
+        }
+        let gap = offset(self.lo, next.lo);
+        let mut steps = BTreeMap::new();
+        for ((&wire, s), n) in self.steps.iter().zip(next.steps.values()) {
+            let rising = if self.len() > 0 {
+                s.rising
+            } else if next.len() > 0 {
+                n.rising
+            } else {
+                n.at_start > s.at_start
+            };
+            let joined = Steps {
+                at_start: s.at_start,
+                rising,
+            };
+            let continues = if rising {
+                s.at_start.checked_add(gap)
+            } else {
+                s.at_start.checked_sub(gap)
+            };
+            if continues != Some(n.at_start) || (next.len() > 0 && n.rising != rising) {
+                return None;
+            }
+            steps.insert(wire, joined);
+        }
+        Some(Self::new(self.lo, next.hi, steps))
+    }
+}
+
+// Distance from `lo` to `along`, which must not come before it.
+fn offset(lo: i64, along: i64) -> u64 {
+    along.wrapping_sub(lo) as u64
+}
+
+impl Segment {
+    pub fn is_horizontal(&self) -> bool {
+        self.start.1 == self.end.1
+    }
+    pub fn len(&self) -> u64 {
+        distance(self.start, self.end)
+    }
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+    // Whether the segment runs towards higher coordinates.
+    fn is_rising(&self) -> bool {
+        self.end.0 > self.start.0 || self.end.1 > self.start.1
+    }
+    // Steps along the wire to `point`, which must lie on the segment.
+    pub fn steps_to(&self, point: Point) -> u64 {
+        self.steps + distance(self.start, point)
+    }
+    // The span the segment covers along its own axis, lowest coordinate first.
+    fn span(&self) -> (i64, i64) {
+        let (a, b) = if self.is_horizontal() {
+            (self.start.0, self.end.0)
+        } else {
+            (self.start.1, self.end.1)
+        };
+        (a.min(b), a.max(b))
+    }
+    // The fixed coordinate: y for horizontal segments, x for vertical ones.
+    fn level(&self) -> i64 {
+        if self.is_horizontal() {
+            self.start.1
+        } else {
+            self.start.0
+        }
+    }
+    fn point_at(&self, along: i64) -> Point {
+        if self.is_horizontal() {
+            (along, self.start.1)
+        } else {
+            (self.start.0, along)
+        }
+    }
+}
+
+impl Wire {
+    // Comma separated moves such as `R8,U5,L5,D3`, starting from the origin.
+    pub fn parse(path: &str) -> Result<Self, ParseError> {
+        let mut wire = Self::default();
+        for (column, token, m) in moves(path) {
+            wire.extend(m?)
+                .map_err(|kind| ParseError::new(column, token, kind))?;
+        }
+        Ok(wire)
+    }
+    // Fails with `OutOfRange` on the first move that leaves the range of
+    // coordinates or of steps.
+    pub fn from_moves(moves: &[Move]) -> Result<Self, ParseErrorKind> {
+        let mut wire = Self::default();
+        for &m in moves {
+            wire.extend(m)?;
+        }
+        Ok(wire)
+    }
+    fn extend(&mut self, m: Move) -> Result<(), ParseErrorKind> {
+        let pos = self.segments.last().map_or((0, 0), |s| s.end);
+        let (dx, dy) = m.direction.delta();
+        let end = i64::try_from(m.len).ok().and_then(|len| {
+            Some((
+                pos.0.checked_add(dx.checked_mul(len)?)?,
+                pos.1.checked_add(dy.checked_mul(len)?)?,
+            ))
+        });
+        self.push(end.ok_or(ParseErrorKind::OutOfRange)?)
+    }
+    // Extends the wire in a straight line to `end`, unless its length would
+    // no longer fit in a u64.
+    pub fn push(&mut self, end: Point) -> Result<(), ParseErrorKind> {
+        let (start, steps) = match self.segments.last() {
+            Some(last) => (last.end, last.steps + last.len()),
+            None => ((0, 0), 0),
+        };
+        assert!(
+            start.0 == end.0 || start.1 == end.1,
+            "wire segments must be horizontal or vertical"
+        );
+        steps
+            .checked_add(distance(start, end))
+            .ok_or(ParseErrorKind::OutOfRange)?;
+        self.segments.push(Segment { start, end, steps });
+        Ok(())
+    }
+    pub fn len(&self) -> u64 {
+        self.segments.last().map_or(0, |s| s.steps + s.len())
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+pub fn distance(a: Point, b: Point) -> u64 {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+}
+
+fn hit(points: &mut BTreeMap<Point, BTreeMap<usize, u64>>, point: Point, wire: usize, steps: u64) {
+    if point != (0, 0) {
+        let best = points
+            .entry(point)
+            .or_default()
+            .entry(wire)
+            .or_insert(steps);
+        *best = (*best).min(steps);
+    }
+}
+
+// Runs along one row or column where segments of at least two wires overlap.
+// The line is split at every segment end, so that the same segments cover
+// each piece. A wire that passes a point twice reaches it first on the
+// segment it walks first, so within a piece its steps follow that segment.
+fn overlaps(line: &[(usize, &Segment)]) -> Vec<Piece> {
+    let mut coords: Vec<i64> = line
+        .iter()
+        .flat_map(|(_, s)| {
+            let (lo, hi) = s.span();
+            vec![lo, hi]
+        })
+        .collect();
+    coords.sort_unstable();
+    coords.dedup();
+    let mut elementary = Vec::new();
+    for (i, &c) in coords.iter().enumerate() {
+        elementary.push((c, c));
+        match coords.get(i + 1) {
+            Some(&next) if c + 1 < next => elementary.push((c + 1, next - 1)),
+            _ => (),
+        }
+    }
+
+    let mut pieces: Vec<Piece> = Vec::new();
+    for (lo, hi) in elementary {
+        let mut first: BTreeMap<usize, &Segment> = BTreeMap::new();
+        for &(wire, s) in line.iter() {
+            let (s_lo, s_hi) = s.span();
+            if s_lo <= lo && hi <= s_hi {
+                let earliest = first.entry(wire).or_insert(s);
+                if s.steps < earliest.steps {
+                    *earliest = s;
+                }
+            }
+        }
+        if first.len() < 2 {
+            continue;
+        }
+        let steps = first
+            .into_iter()
+            .map(|(wire, s)| {
+                let at_start = s.steps_to(s.point_at(lo));
+                let rising = s.is_rising();
+                (wire, Steps { at_start, rising })
+            })
+            .collect();
+        let piece = Piece::new(lo, hi, steps);
+        match pieces.last().and_then(|last| last.join(&piece)) {
+            Some(joined) => *pieces.last_mut().unwrap() = joined,
+            None => pieces.push(piece),
+        }
+    }
+    pieces
+}
+
+// Takes `along` out of the run of `line` containing it, returning the steps
+// of each wire there.
+fn cut(line: &mut BTreeMap<i64, Piece>, along: i64) -> Option<BTreeMap<usize, u64>> {
+    let (&lo, piece) = line.range(..=along).next_back()?;
+    if piece.hi < along {
+        return None;
+    }
+    let piece = line.remove(&lo).unwrap();
+    if piece.lo < along {
+        line.insert(piece.lo, piece.slice(piece.lo, along - 1));
+    }
+    if along < piece.hi {
+        line.insert(along + 1, piece.slice(along + 1, piece.hi));
+    }
+    Some(piece.steps_at(along))
+}
+
+// Every crossing of at least two of `wires` other than the origin, ordered
+// by start and end, with the fewest steps every wire through it needs.
+//
+// Perpendicular segments are matched with a sweep over x that keeps the
+// horizontal segments spanning the current x ordered by y. Collinear
+// overlaps are kept as runs, whatever their length. Points where
+// perpendicular segments meet are listed on their own and cut out of any
+// run through them, so that no point is listed twice.
+pub(crate) fn sweep(wires: &[&Wire]) -> Vec<Crossing> {
+    let mut points: BTreeMap<Point, BTreeMap<usize, u64>> = BTreeMap::new();
+
+    let segments: Vec<(usize, &Segment)> = wires
+        .iter()
+        .enumerate()
+        .flat_map(|(w, wire)| wire.segments.iter().map(move |s| (w, s)))
+        .filter(|(_, s)| !s.is_empty())
+        .collect();
+
+    // (x, order, segment): at equal x, horizontals are inserted before and
+    // removed after the verticals are queried so that end points touch.
+    const INSERT: u8 = 0;
+    const QUERY: u8 = 1;
+    const REMOVE: u8 = 2;
+    let mut events = Vec::new();
+    for (i, (_, s)) in segments.iter().enumerate() {
+        let (lo, hi) = s.span();
+        if s.is_horizontal() {
+            events.push((lo, INSERT, i));
+            events.push((hi, REMOVE, i));
+        } else {
+            events.push((s.level(), QUERY, i));
+        }
+    }
+    events.sort_unstable();
+    let mut active: BTreeMap<i64, Vec<usize>> = BTreeMap::new();
+    for (x, order, i) in events {
+        let (wire, segment) = segments[i];
+        match order {
+            INSERT => active.entry(segment.level()).or_default().push(i),
+            REMOVE => {
+                let at_level = active.get_mut(&segment.level()).unwrap();
+                at_level.retain(|&j| j != i);
+                if at_level.is_empty() {
+                    active.remove(&segment.level());
+                }
+            }
+            _ => {
+                let (lo, hi) = segment.span();
+                for (&y, horizontals) in active.range(lo..=hi) {
+                    for &j in horizontals {
+                        let (other, horizontal) = segments[j];
+                        if other != wire {
+                            let point = (x, y);
+                            hit(&mut points, point, wire, segment.steps_to(point));
+                            hit(&mut points, point, other, horizontal.steps_to(point));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Collinear overlaps, per row and per column.
+    let mut lines: HashMap<(bool, i64), Vec<(usize, &Segment)>> = HashMap::new();
+    for &(wire, s) in segments.iter() {
+        lines
+            .entry((s.is_horizontal(), s.level()))
+            .or_default()
+            .push((wire, s));
+    }
+    let mut runs: HashMap<(bool, i64), BTreeMap<i64, Piece>> = lines
+        .iter()
+        .map(|(&key, line)| (key, overlaps(line).into_iter().map(|p| (p.lo, p)).collect()))
+        .collect();
+    let cuts: Vec<Point> = points.keys().copied().chain(Some((0, 0))).collect();
+    for point in cuts {
+        for &(key, along) in [((true, point.1), point.0), ((false, point.0), point.1)].iter() {
+            let steps = runs.get_mut(&key).and_then(|line| cut(line, along));
+            for (wire, steps) in steps.into_iter().flatten() {
+                hit(&mut points, point, wire, steps);
+            }
+        }
+    }
+
+    let mut crossings: Vec<Crossing> = points
+        .into_iter()
+        .map(|(point, steps)| Crossing::point(point, steps))
+        .collect();
+    for ((horizontal, level), line) in runs {
+        let at = |along| {
+            if horizontal {
+                (along, level)
+            } else {
+                (level, along)
+            }
+        };
+        crossings.extend(line.into_values().map(|piece| Crossing {
+            start: at(piece.lo),
+            end: at(piece.hi),
+            steps: piece.steps,
+        }));
+    }
+    crossings.sort_by_key(|c| (c.start, c.end));
+    crossings
+}
+
+// Every crossing of the two wires, which are numbered 0 and 1.
+pub fn intersect(a: &Wire, b: &Wire) -> Vec<Crossing> {
+    sweep(&[a, b])
+}
+
+pub fn closest_by_distance(crossings: &[Crossing]) -> Option<u64> {
+    crossings.iter().map(Crossing::distance).min()
+}
+
+pub fn closest_by_steps(crossings: &[Crossing]) -> Option<u64> {
+    crossings.iter().map(Crossing::combined_steps).min()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::rng::Rng;
+
+    fn examples() -> Vec<(&'static str, &'static str, u64, u64)> {
+        vec![
+            ("R8,U5,L5,D3", "U7,R6,D4,L4", 6, 30),
+            (
+                "R75,D30,R83,U83,L12,D49,R71,U7,L72",
+                "U62,R66,U55,R34,D71,R55,D58,R83",
+                159,
+                610,
+            ),
+            (
+                "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51",
+                "U98,R91,D20,R16,D67,R40,U7,R15,U6,R7",
+                135,
+                410,
+            ),
+        ]
+    }
+
+    // The per-cell walk the segments replace.
+    fn cells(wire: &Wire) -> HashMap<Point, u64> {
+        let mut cells = HashMap::new();
+        for s in wire.segments.iter() {
+            let dir = (
+                (s.end.0 - s.start.0).signum(),
+                (s.end.1 - s.start.1).signum(),
+            );
+            for k in 1..=s.len() as i64 {
+                let point = (s.start.0 + dir.0 * k, s.start.1 + dir.1 * k);
+                cells.entry(point).or_insert(s.steps + k as u64);
+            }
+        }
+        cells
+    }
+    fn brute_force(wires: &[&Wire]) -> Vec<(Point, BTreeMap<usize, u64>)> {
+        let mut points: BTreeMap<Point, BTreeMap<usize, u64>> = BTreeMap::new();
+        for (i, wire) in wires.iter().enumerate() {
+            for (point, steps) in cells(wire) {
+                points.entry(point).or_default().insert(i, steps);
+            }
+        }
+        points.remove(&(0, 0));
+        points.into_iter().filter(|(_, s)| s.len() >= 2).collect()
+    }
+    // Every point of the crossings, each with the steps of its wires.
+    fn expand(crossings: &[Crossing]) -> Vec<(Point, BTreeMap<usize, u64>)> {
+        let mut points: Vec<_> = crossings
+            .iter()
+            .flat_map(|c| c.points().map(move |p| (p, c.steps_at(p).unwrap())))
+            .collect();
+        points.sort_by_key(|(point, _)| *point);
+        points
+    }
+    fn random_wire(rng: &mut Rng, moves: usize, reach: i64) -> Wire {
+        let mut wire = Wire::default();
+        let mut pos = (0, 0);
+        for _ in 0..moves {
+            let len = rng.range(0, reach);
+            pos = match rng.below(4) {
+                0 => (pos.0 + len, pos.1),
+                1 => (pos.0 - len, pos.1),
+                2 => (pos.0, pos.1 + len),
+                _ => (pos.0, pos.1 - len),
+            };
+            wire.push(pos).unwrap();
+        }
+        wire
+    }
+    fn steps(list: &[(usize, u64, bool)]) -> BTreeMap<usize, Steps> {
+        list.iter()
+            .map(|&(wire, at_start, rising)| (wire, Steps { at_start, rising }))
+            .collect()
+    }
+
+    #[test]
+    fn test_examples() {
+        for (a, b, distance, steps) in examples() {
+            let crossings = intersect(&Wire::parse(a).unwrap(), &Wire::parse(b).unwrap());
+            assert_eq!(closest_by_distance(&crossings), Some(distance));
+            assert_eq!(closest_by_steps(&crossings), Some(steps));
+        }
+        let crossings = intersect(
+            &Wire::parse("R8,U5,L5,D3").unwrap(),
+            &Wire::parse("U7,R6,D4,L4").unwrap(),
+        );
+        assert_eq!(
+            crossings,
+            [
+                Crossing {
+                    start: (3, 3),
+                    end: (3, 3),
+                    steps: steps(&[(0, 20, true), (1, 20, true)])
+                },
+                Crossing {
+                    start: (6, 5),
+                    end: (6, 5),
+                    steps: steps(&[(0, 15, true), (1, 15, true)])
+                },
+            ]
+        );
+    }
+    #[test]
+    fn test_matches_cell_walk() {
+        // Short moves in a small area give plenty of overlaps, touching end
+        // points, self-crossings and returns to the origin.
+        for seed in 0..300 {
+            let mut rng = Rng::new(seed);
+            let a = random_wire(&mut rng, 12, 6);
+            let b = random_wire(&mut rng, 12, 6);
+            let c = random_wire(&mut rng, 12, 6);
+            let crossings = intersect(&a, &b);
+            assert_eq!(expand(&crossings), brute_force(&[&a, &b]), "seed {}", seed);
+            let crossings = sweep(&[&a, &b, &c]);
+            assert_eq!(
+                expand(&crossings),
+                brute_force(&[&a, &b, &c]),
+                "seed {}",
+                seed
+            );
+            for crossing in crossings.iter() {
+                let points: Vec<(Point, u64)> = crossing
+                    .points()
+                    .map(|p| (p, crossing.steps_at(p).unwrap().values().sum()))
+                    .collect();
+                let nearest = points.iter().map(|&(p, _)| distance(p, (0, 0))).min();
+                let quickest = points.iter().map(|&(_, steps)| steps).min();
+                assert_eq!(Some(crossing.distance()), nearest);
+                assert_eq!(Some(crossing.combined_steps()), quickest);
+            }
+        }
+    }
+    #[test]
+    fn test_runs() {
+        // Both wires go right along y = 0, the second doubling back for a
+        // step, which makes it lose 2 steps from x = 4 on.
+        let a = Wire::parse("R10").unwrap();
+        let b = Wire::parse("R3,L1,R8").unwrap();
+        let crossings = intersect(&a, &b);
+        assert_eq!(
+            crossings,
+            [
+                Crossing {
+                    start: (1, 0),
+                    end: (3, 0),
+                    steps: steps(&[(0, 1, true), (1, 1, true)])
+                },
+                Crossing {
+                    start: (4, 0),
+                    end: (10, 0),
+                    steps: steps(&[(0, 4, true), (1, 6, true)])
+                },
+            ]
+        );
+        assert_eq!(crossings[1].size(), 7);
+        assert_eq!(crossings[1].steps_at((10, 0)).unwrap()[&1], 12);
+        assert_eq!(crossings[1].steps_at((11, 0)), None);
+        assert_eq!(closest_by_distance(&crossings), Some(1));
+        assert_eq!(closest_by_steps(&crossings), Some(2));
+
+        // The second wire walks the run the other way, ending at a turn that
+        // is listed on its own.
+        let a = Wire::parse("R10").unwrap();
+        let b = Wire::parse("U1,R10,D1,L10").unwrap();
+        let crossings = intersect(&a, &b);
+        assert_eq!(
+            crossings,
+            [
+                Crossing {
+                    start: (1, 0),
+                    end: (9, 0),
+                    steps: steps(&[(0, 1, true), (1, 21, false)])
+                },
+                Crossing {
+                    start: (10, 0),
+                    end: (10, 0),
+                    steps: steps(&[(0, 10, true), (1, 12, true)])
+                },
+            ]
+        );
+        assert_eq!(crossings[0].quickest(), (1, 0));
+        assert_eq!(crossings[0].combined_steps(), 22);
+        assert_eq!(crossings[0].steps_at((9, 0)).unwrap()[&1], 13);
+    }
+    #[test]
+    fn test_long_wires() {
+        let a = Wire::parse("R2000000000,U2000000000").unwrap();
+        let b = Wire::parse("U1000000000,R3000000000,D5").unwrap();
+        assert_eq!(a.len(), 4_000_000_000);
+        let crossings = intersect(&a, &b);
+        assert_eq!(
+            crossings,
+            [Crossing {
+                start: (2_000_000_000, 1_000_000_000),
+                end: (2_000_000_000, 1_000_000_000),
+                steps: steps(&[(0, 3_000_000_000, true), (1, 3_000_000_000, true)])
+            }]
+        );
+
+        // Overlaps cost the same whatever their length.
+        let a = Wire::parse("R1000000000,U5,L2000000000").unwrap();
+        let b = Wire::parse("L3,R1000000000").unwrap();
+        let crossings = intersect(&a, &b);
+        assert_eq!(crossings.len(), 1);
+        assert_eq!(
+            (crossings[0].start, crossings[0].end),
+            ((1, 0), (999_999_997, 0))
+        );
+        assert_eq!(crossings[0].distance(), 1);
+        assert_eq!(crossings[0].combined_steps(), 1 + 7);
+        assert_eq!(
+            crossings[0].steps_at((999_999_997, 0)).unwrap()[&1],
+            1_000_000_003
+        );
+
+        let mut rng = Rng::new(3);
+        let a = random_wire(&mut rng, 5000, 1_000_000);
+        let b = random_wire(&mut rng, 5000, 1_000_000);
+        let crossings = intersect(&a, &b);
+        assert!(!crossings.is_empty());
+        for crossing in crossings.iter().filter(|c| c.is_point()) {
+            let point = crossing.start;
+            let on = |wire: &Wire| {
+                wire.segments
+                    .iter()
+                    .filter(|s| {
+                        let (lo, hi) = s.span();
+                        let along = if s.is_horizontal() {
+                            (point.1, point.0)
+                        } else {
+                            (point.0, point.1)
+                        };
+                        !s.is_empty() && s.level() == along.0 && lo <= along.1 && along.1 <= hi
+                    })
+                    .map(|s| s.steps_to(point))
+                    .min()
+            };
+            let steps = crossing.steps_at(point).unwrap();
+            assert_eq!((on(&a), on(&b)), (Some(steps[&0]), Some(steps[&1])));
+        }
+    }
+    #[test]
+    fn test_parse_moves() {
+        assert_eq!(
+            parse_moves(" r8, U5 ,l 5,D3\n"),
+            Ok(vec![
+                Move {
+                    direction: Direction::Right,
+                    len: 8
+                },
+                Move {
+                    direction: Direction::Up,
+                    len: 5
+                },
+                Move {
+                    direction: Direction::Left,
+                    len: 5
+                },
+                Move {
+                    direction: Direction::Down,
+                    len: 3
+                },
+            ])
+        );
+        assert_eq!(parse_moves(""), Ok(vec![]));
+        assert_eq!(
+            Wire::from_moves(&parse_moves("R8,U5,L5,D3").unwrap()),
+            Ok(Wire::parse("R8,U5,L5,D3").unwrap())
+        );
+        assert_eq!(
+            parse_moves("R18446744073709551615").unwrap_err().kind,
+            ParseErrorKind::OutOfRange
+        );
+        let far = Move {
+            direction: Direction::Right,
+            len: u64::MAX,
+        };
+        assert_eq!(Wire::from_moves(&[far]), Err(ParseErrorKind::OutOfRange));
+        let half = Move {
+            len: i64::MAX as u64,
+            ..far
+        };
+        let back = Move {
+            direction: Direction::Left,
+            ..half
+        };
+        assert_eq!(
+            Wire::from_moves(&[half, back, half]),
+            Err(ParseErrorKind::OutOfRange)
+        );
+        let mut wire = Wire::from_moves(&[half, back]).unwrap();
+        assert_eq!(wire.len(), u64::MAX - 1);
+        assert_eq!(wire.push((2, 0)), Err(ParseErrorKind::OutOfRange));
+        assert_eq!(wire.push((1, 0)), Ok(()));
+        assert_eq!(wire.len(), u64::MAX);
+
+        let error = |path| Wire::parse(path).unwrap_err();
+        assert_eq!(
+            error("R8,X5"),
+            ParseError {
+                line: 1,
+                column: 4,
+                token: "X5".to_string(),
+                kind: ParseErrorKind::UnknownDirection('X')
+            }
+        );
+        assert_eq!(
+            error("R8,X5").to_string(),
+            "line 1, column 4: unknown direction `X` in `X5`"
+        );
+        assert_eq!(error("R8, U").kind, ParseErrorKind::InvalidLength);
+        assert_eq!(error("R8, U").column, 5);
+        assert_eq!(error("R8,U-3").kind, ParseErrorKind::InvalidLength);
+        assert_eq!(error("R8,U3x").kind, ParseErrorKind::InvalidLength);
+        assert_eq!(
+            error("R8,,U3").to_string(),
+            "line 1, column 4: missing move"
+        );
+        assert_eq!(error("R8,").kind, ParseErrorKind::Empty);
+        assert_eq!(
+            error("R99999999999999999999").kind,
+            ParseErrorKind::OutOfRange
+        );
+        assert_eq!(
+            error("R9223372036854775807,R1").to_string(),
+            "line 1, column 22: `R1` goes out of range"
+        );
+        let max = "9223372036854775807";
+        let path = format!("R{0},L{0},R{0},L{0},R1", max);
+        assert_eq!(
+            error(&path).to_string(),
+            format!("line 1, column 43: `R{}` goes out of range", max)
+        );
+    }
+}
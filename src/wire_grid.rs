@@ -0,0 +1,371 @@
+use crate::wire::{sweep, Crossing, ParseError, Point, Wire};
+use std::collections::HashMap;
+
+const COLORS: [&str; 6] = [
+    "#1f77b4", "#ff7f0e", "#2ca02c", "#9467bd", "#8c564b", "#17becf",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WireGrid {
+    wires: Vec<Wire>,
+    crossings: Vec<Crossing>,
+}
+
+impl WireGrid {
+    pub fn new(wires: Vec<Wire>) -> Self {
+        let crossings = sweep(&wires.iter().collect::<Vec<_>>());
+        Self { wires, crossings }
+    }
+    // One wire path per line, skipping blank lines.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let wires = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| Wire::parse(line).map_err(|e| ParseError { line: i + 1, ..e }))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::new(wires))
+    }
+    pub fn wires(&self) -> &[Wire] {
+        &self.wires
+    }
+    // Every crossing of at least two wires, ordered by start point.
+    pub fn crossings(&self) -> &[Crossing] {
+        &self.crossings
+    }
+    // Crossings where at least `k` different wires meet.
+    pub fn shared_by(&self, k: usize) -> impl Iterator<Item = &Crossing> {
+        self.crossings.iter().filter(move |c| c.steps.len() >= k)
+    }
+    pub fn shared_by_all(&self) -> impl Iterator<Item = &Crossing> {
+        self.shared_by(self.wires.len().max(2))
+    }
+    // Crossings of two given wires, with the steps of just those two, whatever
+    // other wires pass there too.
+    pub fn between(&self, a: usize, b: usize) -> Vec<Crossing> {
+        self.crossings
+            .iter()
+            .filter(|c| a != b && c.has_wire(a) && c.has_wire(b))
+            .map(|c| Crossing {
+                steps: c
+                    .steps
+                    .iter()
+                    .filter(|(&wire, _)| wire == a || wire == b)
+                    .map(|(&wire, &steps)| (wire, steps))
+                    .collect(),
+                ..c.clone()
+            })
+            .collect()
+    }
+    // The crossing of at least `k` wires minimizing `metric`; ties go to the
+    // lowest start point.
+    pub fn closest_by<F: Fn(&Crossing) -> u64>(&self, k: usize, metric: F) -> Option<&Crossing> {
+        self.shared_by(k).min_by_key(|c| metric(c))
+    }
+    // The crossing with the point nearest to the origin, found by `nearest`.
+    pub fn closest_by_distance(&self, k: usize) -> Option<&Crossing> {
+        self.closest_by(k, Crossing::distance)
+    }
+    // The crossing with the point the wires reach in the fewest steps, found
+    // by `quickest`.
+    pub fn closest_by_steps(&self, k: usize) -> Option<&Crossing> {
+        self.closest_by(k, Crossing::combined_steps)
+    }
+
+    // Smallest and largest x and y over the origin and every wire.
+    pub fn bounds(&self) -> (Point, Point) {
+        let points = self
+            .wires
+            .iter()
+            .flat_map(|wire| wire.segments.iter().map(|s| s.end));
+        points.fold(((0, 0), (0, 0)), |(min, max), (x, y)| {
+            ((min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y)))
+        })
+    }
+    // Width and height of the bounds, which may not fit in an i64.
+    pub fn extent(&self) -> (u64, u64) {
+        let (min, max) = self.bounds();
+        (max.0.abs_diff(min.0), max.1.abs_diff(min.1))
+    }
+    // One character per cell, y pointing up, with a one cell margin: `o` is
+    // the origin, `+` a turn, `X` a crossing and `*` the `highlight`ed one.
+    // Meant for small grids, as every cell of every wire is drawn.
+    pub fn to_ascii(&self, highlight: Option<Point>) -> String {
+        let mut cells: HashMap<Point, char> = HashMap::new();
+        for wire in self.wires.iter() {
+            let last = wire.segments.len().saturating_sub(1);
+            for (i, s) in wire.segments.iter().enumerate() {
+                let line = if s.is_horizontal() { '-' } else { '|' };
+                let dir = (
+                    (s.end.0 - s.start.0).signum(),
+                    (s.end.1 - s.start.1).signum(),
+                );
+                for k in 0..=s.len() as i64 {
+                    let point = (s.start.0 + dir.0 * k, s.start.1 + dir.1 * k);
+                    let turn = k == 0 || (k == s.len() as i64 && i != last);
+                    let c = cells.entry(point).or_insert(line);
+                    if turn || *c != line {
+                        *c = '+';
+                    }
+                }
+            }
+        }
+        for crossing in self.crossings.iter() {
+            cells.extend(crossing.points().map(|point| (point, 'X')));
+        }
+        if let Some(point) = highlight {
+            cells.insert(point, '*');
+        }
+        cells.insert((0, 0), 'o');
+
+        let (min, max) = self.bounds();
+        let mut ascii = String::new();
+        for y in (min.1 - 1..=max.1 + 1).rev() {
+            for x in min.0 - 1..=max.0 + 1 {
+                ascii.push(*cells.get(&(x, y)).unwrap_or(&'.'));
+            }
+            ascii.push('\n');
+        }
+        ascii
+    }
+    // An SVG scaled so that its longest side is `size` pixels, one polyline
+    // per wire, a dot on each crossing, a thick line along each run of them
+    // and a ring around the `highlight`.
+    pub fn to_svg(&self, highlight: Option<Point>, size: u32) -> String {
+        let (min, max) = self.bounds();
+        let (width, height) = self.extent();
+        let extent = width.max(height).max(1) as f64;
+        let margin = extent / 20.0;
+        let (width, height) = (width as f64, height as f64);
+        let scale = f64::from(size) / (extent + 2.0 * margin);
+        // One pixel in grid units, so that strokes and markers look the same
+        // at any scale.
+        let px = |n: f64| num(n / scale);
+        let view = format!(
+            "x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"",
+            num(min.0 as f64 - margin),
+            num(-(max.1 as f64) - margin),
+            num(width + 2.0 * margin),
+            num(height + 2.0 * margin),
+        );
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+             viewBox=\"{} {} {} {}\">\n  <rect {} fill=\"white\"/>\n",
+            ((width + 2.0 * margin) * scale).round(),
+            ((height + 2.0 * margin) * scale).round(),
+            num(min.0 as f64 - margin),
+            num(-(max.1 as f64) - margin),
+            num(width + 2.0 * margin),
+            num(height + 2.0 * margin),
+            view
+        );
+        for (i, wire) in self.wires.iter().enumerate() {
+            let mut points = String::from("0,0");
+            for s in wire.segments.iter() {
+                points.push_str(&format!(" {},{}", s.end.0, flip(s.end.1)));
+            }
+            svg.push_str(&format!(
+                "  <polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\"/>\n",
+                points,
+                COLORS[i % COLORS.len()],
+                px(2.0)
+            ));
+        }
+        for crossing in self.crossings.iter() {
+            let (start, end) = (crossing.start, crossing.end);
+            if crossing.is_point() {
+                svg.push_str(&format!(
+                    "  <circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"black\"/>\n",
+                    start.0,
+                    flip(start.1),
+                    px(4.0)
+                ));
+            } else {
+                svg.push_str(&format!(
+                    "  <line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"black\" \
+                     stroke-width=\"{}\" stroke-linecap=\"round\"/>\n",
+                    start.0,
+                    flip(start.1),
+                    end.0,
+                    flip(end.1),
+                    px(8.0)
+                ));
+            }
+        }
+        if let Some(point) = highlight {
+            svg.push_str(&format!(
+                "  <circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"none\" stroke=\"red\" stroke-width=\"{}\"/>\n",
+                point.0,
+                flip(point.1),
+                px(10.0),
+                px(2.0)
+            ));
+        }
+        svg.push_str(&format!(
+            "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"black\"/>\n",
+            px(-4.0),
+            px(-4.0),
+            px(8.0),
+            px(8.0)
+        ));
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+// SVG y points down; i64::MIN has no i64 opposite.
+fn flip(y: i64) -> i128 {
+    -i128::from(y)
+}
+
+// At most three decimals, without trailing zeros.
+fn num(n: f64) -> String {
+    let s = format!("{:.3}", n);
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    if s == "-0" {
+        "0".to_string()
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    const WIRES: &str = "R8,U5,L5,D3\nU7,R6,D4,L4\nL1,U3,R5,U6\n\nU2,R10\n";
+
+    #[test]
+    fn test_two_wires() {
+        let grid = WireGrid::parse("R8,U5,L5,D3\nU7,R6,D4,L4\n").unwrap();
+        assert_eq!(grid.wires().len(), 2);
+        assert_eq!(grid.closest_by_distance(2).unwrap().distance(), 6);
+        assert_eq!(grid.closest_by_steps(2).unwrap().combined_steps(), 30);
+        assert_eq!(
+            grid.between(0, 1),
+            crate::wire::intersect(&grid.wires()[0], &grid.wires()[1])
+        );
+        assert_eq!(grid.shared_by_all().count(), 2);
+        assert_eq!(
+            WireGrid::parse("R8\n\nU2,q1").unwrap_err().to_string(),
+            "line 3, column 4: unknown direction `q` in `q1`"
+        );
+    }
+    #[test]
+    fn test_many_wires() {
+        let grid = WireGrid::parse(WIRES).unwrap();
+        assert_eq!(grid.wires().len(), 4);
+        let points: Vec<(Point, Vec<usize>)> = grid
+            .crossings()
+            .iter()
+            .map(|c| (c.start, c.wires()))
+            .collect();
+        assert_eq!(
+            points,
+            [
+                ((0, 1), vec![1, 3]),
+                ((0, 2), vec![1, 3]),
+                ((0, 3), vec![1, 2]),
+                ((2, 3), vec![1, 2]),
+                ((3, 2), vec![0, 3]),
+                ((3, 3), vec![0, 1, 2]),
+                ((4, 3), vec![1, 2]),
+                ((4, 5), vec![0, 2]),
+                ((4, 7), vec![1, 2]),
+                ((6, 5), vec![0, 1]),
+                ((8, 2), vec![0, 3]),
+            ]
+        );
+        assert_eq!(grid.shared_by(3).count(), 1);
+        assert_eq!(grid.shared_by_all().count(), 0);
+
+        let closest = grid.closest_by_distance(2).unwrap();
+        assert_eq!((closest.nearest(), closest.distance()), ((0, 1), 1));
+        let closest = grid.closest_by_distance(3).unwrap();
+        assert_eq!(closest.start, (3, 3));
+        let steps = closest.steps_at((3, 3)).unwrap();
+        assert_eq!(steps.values().collect::<Vec<_>>(), [&20, &20, &8]);
+        assert_eq!(closest.combined_steps(), 48);
+        assert_eq!(grid.closest_by_steps(2).unwrap().quickest(), (0, 1));
+
+        // Furthest along wire 0, as an example of a custom metric.
+        let latest = grid
+            .closest_by(2, |c| u64::MAX - c.steps.get(&0).map_or(0, |s| s.at_start))
+            .unwrap();
+        assert_eq!(latest.start, (3, 2));
+        let between: Vec<(Point, Vec<u64>)> = grid
+            .between(0, 3)
+            .iter()
+            .map(|c| {
+                (
+                    c.start,
+                    c.steps_at(c.start).unwrap().values().copied().collect(),
+                )
+            })
+            .collect();
+        assert_eq!(between, [((3, 2), vec![21, 5]), ((8, 2), vec![10, 10])]);
+        assert!(grid.between(2, 2).is_empty());
+    }
+    #[test]
+    fn test_long_overlap() {
+        // One run, however many points the wires share.
+        let grid = WireGrid::parse("R1000000000\nL2,R1000000002\n").unwrap();
+        assert_eq!(grid.crossings().len(), 1);
+        let run = &grid.crossings()[0];
+        assert_eq!((run.start, run.end), ((1, 0), (1_000_000_000, 0)));
+        assert_eq!(run.size(), 1_000_000_000);
+        assert_eq!(grid.closest_by_distance(2).unwrap().distance(), 1);
+        assert_eq!(grid.closest_by_steps(2).unwrap().combined_steps(), 1 + 5);
+    }
+    #[test]
+    fn test_to_ascii() {
+        let grid = WireGrid::parse("R8,U5,L5,D3\nU7,R6,D4,L4\n").unwrap();
+        let closest = grid.closest_by_distance(2).map(Crossing::nearest);
+        assert_eq!(
+            grid.to_ascii(closest),
+            "\
+...........
+.+-----+...
+.|.....|...
+.|..+--X-+.
+.|..|..|.|.
+.|.-*--+.|.
+.|..|....|.
+.|.......|.
+.o-------+.
+...........
+"
+        );
+        assert_eq!(WireGrid::default().to_ascii(None), "...\n.o.\n...\n");
+    }
+    #[test]
+    fn test_to_svg() {
+        let grid = WireGrid::parse("R8,U5,L5,D3\nU7,R6,D4,L4\nL2").unwrap();
+        assert_eq!(grid.bounds(), ((-2, 0), (8, 7)));
+        let svg = grid.to_svg(Some((3, 3)), 500);
+        assert!(svg.starts_with(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"500\" height=\"364\" \
+             viewBox=\"-2.5 -7.5 11 8\">"
+        ));
+        assert!(svg.contains(
+            "<polyline points=\"0,0 8,0 8,-5 3,-5 3,-2\" fill=\"none\" stroke=\"#1f77b4\""
+        ));
+        assert_eq!(svg.matches("<polyline").count(), 3);
+        assert_eq!(svg.matches("fill=\"black\"/>").count(), 3);
+        assert!(svg.contains("<circle cx=\"3\" cy=\"-3\" r=\"0.22\" fill=\"none\" stroke=\"red\""));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+    #[test]
+    fn test_to_svg_extreme_coordinates() {
+        let grid = WireGrid::parse(
+            "R9223372036854775807\nL9223372036854775807,L1\nD9223372036854775807,D1\n",
+        )
+        .unwrap();
+        assert_eq!(grid.bounds(), ((i64::MIN, i64::MIN), (i64::MAX, 0)));
+        assert_eq!(grid.extent(), (u64::MAX, 1 << 63));
+        let svg = grid.to_svg(None, 1000);
+        assert!(svg.contains("points=\"0,0 -9223372036854775807,0 -9223372036854775808,0\""));
+        assert!(svg.contains("points=\"0,0 0,9223372036854775807 0,9223372036854775808\""));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+}
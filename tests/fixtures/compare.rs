@@ -0,0 +1,188 @@
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod compare {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 47] = [3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36, 98, 0, 0, 1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104, 999, 1105, 1, 46, 1101, 1000, 1, 20, 4, 20, 1105, 1, 46, 98, 99];
+    const COMPILED: [bool; 47] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, true];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: in [21]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(0);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(21, value);
+                    }
+                    // 2: eq [21], 8, [20]
+                    {
+                        let a: i64 = *vm.memory.entry(21).or_insert(0);
+                        let b: i64 = 8;
+                        steps += 1;
+                        vm.memory.insert(20, (a == b) as i64);
+                    }
+                    // 6: jt [20], 22
+                    {
+                        let cond: i64 = *vm.memory.entry(20).or_insert(0);
+                        let target: i64 = 22;
+                        ip = if cond != 0 { target as usize } else { 9 };
+                        steps += 1;
+                    }
+                }
+                9 => {
+                    // 9: lt 8, [21], [20]
+                    {
+                        let a: i64 = 8;
+                        let b: i64 = *vm.memory.entry(21).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(20, (a < b) as i64);
+                    }
+                    // 13: jf [20], 31
+                    {
+                        let cond: i64 = *vm.memory.entry(20).or_insert(0);
+                        let target: i64 = 31;
+                        ip = if cond == 0 { target as usize } else { 16 };
+                        steps += 1;
+                    }
+                }
+                16 => {
+                    // 16: jf 0, 36
+                    {
+                        let cond: i64 = 0;
+                        let target: i64 = 36;
+                        ip = if cond == 0 { target as usize } else { 19 };
+                        steps += 1;
+                    }
+                }
+                22 => {
+                    // 22: mul [21], 125, [20]
+                    {
+                        let a: i64 = *vm.memory.entry(21).or_insert(0);
+                        let b: i64 = 125;
+                        steps += 1;
+                        vm.memory.insert(20, a * b);
+                    }
+                    // 26: out [20]
+                    {
+                        stdout.push(*vm.memory.entry(20).or_insert(0));
+                        steps += 1;
+                    }
+                    // 28: jt 1, 46
+                    {
+                        let cond: i64 = 1;
+                        let target: i64 = 46;
+                        ip = if cond != 0 { target as usize } else { 31 };
+                        steps += 1;
+                    }
+                }
+                31 => {
+                    // 31: out 999
+                    {
+                        stdout.push(999);
+                        steps += 1;
+                    }
+                    // 33: jt 1, 46
+                    {
+                        let cond: i64 = 1;
+                        let target: i64 = 46;
+                        ip = if cond != 0 { target as usize } else { 36 };
+                        steps += 1;
+                    }
+                }
+                36 => {
+                    // 36: add 1000, 1, [20]
+                    {
+                        let a: i64 = 1000;
+                        let b: i64 = 1;
+                        steps += 1;
+                        vm.memory.insert(20, a + b);
+                    }
+                    // 40: out [20]
+                    {
+                        stdout.push(*vm.memory.entry(20).or_insert(0));
+                        steps += 1;
+                    }
+                    // 42: jt 1, 46
+                    {
+                        let cond: i64 = 1;
+                        let target: i64 = 46;
+                        ip = if cond != 0 { target as usize } else { 45 };
+                        steps += 1;
+                    }
+                }
+                46 => {
+                    // 46: halt
+                    vm.set_ip(46);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,152 @@
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod feedback_loop {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 29] = [3, 26, 1001, 26, -4, 26, 3, 27, 1002, 27, 2, 27, 1, 27, 26, 27, 4, 27, 1001, 28, -1, 28, 1005, 28, 6, 99, 0, 0, 5];
+    const COMPILED: [bool; 29] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: in [26]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(0);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(26, value);
+                    }
+                    // 2: add [26], -4, [26]
+                    {
+                        let a: i64 = *vm.memory.entry(26).or_insert(0);
+                        let b: i64 = -4;
+                        steps += 1;
+                        vm.memory.insert(26, a + b);
+                    }
+                    ip = 6;
+                }
+                6 => {
+                    // 6: in [27]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(6);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(27, value);
+                    }
+                    // 8: mul [27], 2, [27]
+                    {
+                        let a: i64 = *vm.memory.entry(27).or_insert(0);
+                        let b: i64 = 2;
+                        steps += 1;
+                        vm.memory.insert(27, a * b);
+                    }
+                    // 12: add [27], [26], [27]
+                    {
+                        let a: i64 = *vm.memory.entry(27).or_insert(0);
+                        let b: i64 = *vm.memory.entry(26).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(27, a + b);
+                    }
+                    // 16: out [27]
+                    {
+                        stdout.push(*vm.memory.entry(27).or_insert(0));
+                        steps += 1;
+                    }
+                    // 18: add [28], -1, [28]
+                    {
+                        let a: i64 = *vm.memory.entry(28).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(28, a + b);
+                    }
+                    // 22: jt [28], 6
+                    {
+                        let cond: i64 = *vm.memory.entry(28).or_insert(0);
+                        let target: i64 = 6;
+                        ip = if cond != 0 { target as usize } else { 25 };
+                        steps += 1;
+                    }
+                }
+                25 => {
+                    // 25: halt
+                    vm.set_ip(25);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,8465 @@
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_0 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 69] = [21002, 61, 13, 61, 20101, 4, 62, 63, 1206, 37, 45, 3, 63, 109, 3, 2201, 51, 23, 66, 207, 28, 21, 64, 7, 14, 63, 62, 1005, 67, 56, 107, 19, 2, 63, 109, 4, 1001, 59, -1, 59, 1005, 59, 30, 104, -4, 3, 67, 109, 0, 1001, 60, -1, 60, 1005, 60, 47, 3, 67, 99, 4, 4, 5, -1, 3, 1, -10, -1, 5, 5];
+    const COMPILED: [bool; 69] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: mul [61], 13, [rb+61]
+                    {
+                        let a: i64 = *vm.memory.entry(61).or_insert(0);
+                        let b: i64 = 13;
+                        steps += 1;
+                        let addr = (rb + 61) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 4, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 4: add 4, [62], [rb+63]
+                    {
+                        let a: i64 = 4;
+                        let b: i64 = *vm.memory.entry(62).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 63) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 8, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 8: jf [rb+37], 45
+                    {
+                        let cond: i64 = *vm.memory.entry((rb + 37) as usize).or_insert(0);
+                        let target: i64 = 45;
+                        ip = if cond == 0 { target as usize } else { 11 };
+                        steps += 1;
+                    }
+                }
+                11 => {
+                    // 11: in [63]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(11);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(63, value);
+                    }
+                    // 13: arb 3
+                    {
+                        rb += 3;
+                        steps += 1;
+                    }
+                    // 15: add [rb+51], [rb+23], [66]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 51) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 23) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(66, a + b);
+                    }
+                    // 19: lt [rb+28], [21], [64]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 28) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(21).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(64, (a < b) as i64);
+                    }
+                    // 23: lt [14], [63], [62]
+                    {
+                        let a: i64 = *vm.memory.entry(14).or_insert(0);
+                        let b: i64 = *vm.memory.entry(63).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(62, (a < b) as i64);
+                    }
+                    // 27: jt [67], 56
+                    {
+                        let cond: i64 = *vm.memory.entry(67).or_insert(0);
+                        let target: i64 = 56;
+                        ip = if cond != 0 { target as usize } else { 30 };
+                        steps += 1;
+                    }
+                }
+                30 => {
+                    // 30: lt 19, [2], [63]
+                    {
+                        let a: i64 = 19;
+                        let b: i64 = *vm.memory.entry(2).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(63, (a < b) as i64);
+                    }
+                    // 34: arb 4
+                    {
+                        rb += 4;
+                        steps += 1;
+                    }
+                    // 36: add [59], -1, [59]
+                    {
+                        let a: i64 = *vm.memory.entry(59).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(59, a + b);
+                    }
+                    // 40: jt [59], 30
+                    {
+                        let cond: i64 = *vm.memory.entry(59).or_insert(0);
+                        let target: i64 = 30;
+                        ip = if cond != 0 { target as usize } else { 43 };
+                        steps += 1;
+                    }
+                }
+                43 => {
+                    // 43: out -4
+                    {
+                        stdout.push(-4);
+                        steps += 1;
+                    }
+                    ip = 45;
+                }
+                45 => {
+                    // 45: in [67]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(45);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(67, value);
+                    }
+                    ip = 47;
+                }
+                47 => {
+                    // 47: arb 0
+                    {
+                        rb += 0;
+                        steps += 1;
+                    }
+                    // 49: add [60], -1, [60]
+                    {
+                        let a: i64 = *vm.memory.entry(60).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(60, a + b);
+                    }
+                    // 53: jt [60], 47
+                    {
+                        let cond: i64 = *vm.memory.entry(60).or_insert(0);
+                        let target: i64 = 47;
+                        ip = if cond != 0 { target as usize } else { 56 };
+                        steps += 1;
+                    }
+                }
+                56 => {
+                    // 56: in [67]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(56);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(67, value);
+                    }
+                    // 58: halt
+                    vm.set_ip(58);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_1 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 114] = [2, 5, 29, 110, 1105, 1, 17, 1105, 14, 10, 1105, 18, 13, 207, 44, 107, 107, 20007, 111, 20, 108, 1001, 104, -1, 104, 1005, 104, 4, 1001, 108, -13, 106, 1001, 112, -12, 108, 1206, 3, 93, 1008, 107, 17, 112, 1206, 25, 89, 1107, -14, -13, 106, 20007, 106, 109, 110, 1206, 12, 69, 21108, -11, -5, 110, 7, 106, 108, 111, 22002, 112, 36, 111, 21001, 109, -5, 111, 1006, 104, 80, 2001, 37, 35, 111, 1001, 105, -1, 105, 1005, 105, 69, 203, 110, 1101, -14, -1, 112, 20108, -16, 108, 113, 109, 4, 101, -17, 104, 112, 99, 1, 2, -5, -10, -1, -7, -5, -6, -9, 1];
+    const COMPILED: [bool; 114] = [true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false, false, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: mul [5], [29], [110]
+                    {
+                        let a: i64 = *vm.memory.entry(5).or_insert(0);
+                        let b: i64 = *vm.memory.entry(29).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(110, a * b);
+                    }
+                    ip = 4;
+                }
+                4 => {
+                    // 4: jt 1, 17
+                    {
+                        let cond: i64 = 1;
+                        let target: i64 = 17;
+                        ip = if cond != 0 { target as usize } else { 7 };
+                        steps += 1;
+                    }
+                }
+                17 => {
+                    // 17: lt [111], [20], [rb+108]
+                    {
+                        let a: i64 = *vm.memory.entry(111).or_insert(0);
+                        let b: i64 = *vm.memory.entry(20).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 108) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 21, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 21: add [104], -1, [104]
+                    {
+                        let a: i64 = *vm.memory.entry(104).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(104, a + b);
+                    }
+                    // 25: jt [104], 4
+                    {
+                        let cond: i64 = *vm.memory.entry(104).or_insert(0);
+                        let target: i64 = 4;
+                        ip = if cond != 0 { target as usize } else { 28 };
+                        steps += 1;
+                    }
+                }
+                28 => {
+                    // 28: add [108], -13, [106]
+                    {
+                        let a: i64 = *vm.memory.entry(108).or_insert(0);
+                        let b: i64 = -13;
+                        steps += 1;
+                        vm.memory.insert(106, a + b);
+                    }
+                    // 32: add [112], -12, [108]
+                    {
+                        let a: i64 = *vm.memory.entry(112).or_insert(0);
+                        let b: i64 = -12;
+                        steps += 1;
+                        vm.memory.insert(108, a + b);
+                    }
+                    // 36: jf [rb+3], 93
+                    {
+                        let cond: i64 = *vm.memory.entry((rb + 3) as usize).or_insert(0);
+                        let target: i64 = 93;
+                        ip = if cond == 0 { target as usize } else { 39 };
+                        steps += 1;
+                    }
+                }
+                39 => {
+                    // 39: eq [107], 17, [112]
+                    {
+                        let a: i64 = *vm.memory.entry(107).or_insert(0);
+                        let b: i64 = 17;
+                        steps += 1;
+                        vm.memory.insert(112, (a == b) as i64);
+                    }
+                    // 43: jf [rb+25], 89
+                    {
+                        let cond: i64 = *vm.memory.entry((rb + 25) as usize).or_insert(0);
+                        let target: i64 = 89;
+                        ip = if cond == 0 { target as usize } else { 46 };
+                        steps += 1;
+                    }
+                }
+                46 => {
+                    // 46: lt -14, -13, [106]
+                    {
+                        let a: i64 = -14;
+                        let b: i64 = -13;
+                        steps += 1;
+                        vm.memory.insert(106, (a < b) as i64);
+                    }
+                    // 50: lt [106], [109], [rb+110]
+                    {
+                        let a: i64 = *vm.memory.entry(106).or_insert(0);
+                        let b: i64 = *vm.memory.entry(109).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 110) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 54, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 54: jf [rb+12], 69
+                    {
+                        let cond: i64 = *vm.memory.entry((rb + 12) as usize).or_insert(0);
+                        let target: i64 = 69;
+                        ip = if cond == 0 { target as usize } else { 57 };
+                        steps += 1;
+                    }
+                }
+                57 => {
+                    // 57: eq -11, -5, [rb+110]
+                    {
+                        let a: i64 = -11;
+                        let b: i64 = -5;
+                        steps += 1;
+                        let addr = (rb + 110) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 61, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 61: lt [106], [108], [111]
+                    {
+                        let a: i64 = *vm.memory.entry(106).or_insert(0);
+                        let b: i64 = *vm.memory.entry(108).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(111, (a < b) as i64);
+                    }
+                    // 65: mul [112], [rb+36], [rb+111]
+                    {
+                        let a: i64 = *vm.memory.entry(112).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 36) as usize).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 111) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 69, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 69;
+                }
+                69 => {
+                    // 69: add [109], -5, [rb+111]
+                    {
+                        let a: i64 = *vm.memory.entry(109).or_insert(0);
+                        let b: i64 = -5;
+                        steps += 1;
+                        let addr = (rb + 111) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 73, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 73: jf [104], 80
+                    {
+                        let cond: i64 = *vm.memory.entry(104).or_insert(0);
+                        let target: i64 = 80;
+                        ip = if cond == 0 { target as usize } else { 76 };
+                        steps += 1;
+                    }
+                }
+                76 => {
+                    // 76: add [37], [rb+35], [111]
+                    {
+                        let a: i64 = *vm.memory.entry(37).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 35) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(111, a + b);
+                    }
+                    ip = 80;
+                }
+                80 => {
+                    // 80: add [105], -1, [105]
+                    {
+                        let a: i64 = *vm.memory.entry(105).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(105, a + b);
+                    }
+                    // 84: jt [105], 69
+                    {
+                        let cond: i64 = *vm.memory.entry(105).or_insert(0);
+                        let target: i64 = 69;
+                        ip = if cond != 0 { target as usize } else { 87 };
+                        steps += 1;
+                    }
+                }
+                87 => {
+                    // 87: in [rb+110]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(87);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 110) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 89, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 89;
+                }
+                89 => {
+                    // 89: add -14, -1, [112]
+                    {
+                        let a: i64 = -14;
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(112, a + b);
+                    }
+                    ip = 93;
+                }
+                93 => {
+                    // 93: eq -16, [108], [rb+113]
+                    {
+                        let a: i64 = -16;
+                        let b: i64 = *vm.memory.entry(108).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 113) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 97, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 97: arb 4
+                    {
+                        rb += 4;
+                        steps += 1;
+                    }
+                    // 99: add -17, [104], [112]
+                    {
+                        let a: i64 = -17;
+                        let b: i64 = *vm.memory.entry(104).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(112, a + b);
+                    }
+                    // 103: halt
+                    vm.set_ip(103);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_2 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 27] = [2007, 46, 56, 23, 109, 3, 2008, 24, 19, 19, 1101, 7, 16, 26, 2107, 13, 1, 25, 99, 1, 5, 8, 3, -7, -9, -7, -3];
+    const COMPILED: [bool; 27] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: lt [46], [rb+56], [23]
+                    {
+                        let a: i64 = *vm.memory.entry(46).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 56) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(23, (a < b) as i64);
+                    }
+                    // 4: arb 3
+                    {
+                        rb += 3;
+                        steps += 1;
+                    }
+                    // 6: eq [24], [rb+19], [19]
+                    {
+                        let a: i64 = *vm.memory.entry(24).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 19) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(19, (a == b) as i64);
+                    }
+                    // 10: add 7, 16, [26]
+                    {
+                        let a: i64 = 7;
+                        let b: i64 = 16;
+                        steps += 1;
+                        vm.memory.insert(26, a + b);
+                    }
+                    // 14: lt 13, [rb+1], [25]
+                    {
+                        let a: i64 = 13;
+                        let b: i64 = *vm.memory.entry((rb + 1) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(25, (a < b) as i64);
+                    }
+                    // 18: halt
+                    vm.set_ip(18);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_3 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 160] = [1006, 153, 34, 2002, 157, 40, 154, 22102, -11, 9, 153, 104, -20, 20008, 152, 159, 155, 21007, 48, 3, 156, 1206, 11, 24, 1005, 155, 27, 1001, 148, -1, 148, 1005, 148, 11, 201, 52, 154, 153, 1205, 61, 147, 3, 156, 109, 0, 3, 159, 20201, 17, 157, 155, 2107, -20, 55, 155, 1001, 153, -8, 157, 2008, 148, 8, 157, 104, -13, 204, 16, 1001, 149, -1, 149, 1005, 149, 51, 1005, 16, 83, 1106, 18, 80, 1106, 1, 83, 1206, 53, 90, 1002, 157, 0, 157, 1002, 46, -15, 158, 1001, 150, -1, 150, 1005, 150, 74, 21201, 24, -4, 155, 4, 149, 104, -17, 22007, 154, 26, 159, 1006, 50, 122, 109, 1, 3, 157, 3, 158, 203, 157, 1108, 18, -1, 152, 1001, 151, -1, 151, 1005, 151, 113, 1107, 9, -14, 158, 208, 29, 157, 154, 21108, 12, 5, 152, 99, 3, 2, 4, 4, -3, 2, 0, -5, 2, -2, -5, -8];
+    const COMPILED: [bool; 160] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: jf [153], 34
+                    {
+                        let cond: i64 = *vm.memory.entry(153).or_insert(0);
+                        let target: i64 = 34;
+                        ip = if cond == 0 { target as usize } else { 3 };
+                        steps += 1;
+                    }
+                }
+                3 => {
+                    // 3: mul [157], [rb+40], [154]
+                    {
+                        let a: i64 = *vm.memory.entry(157).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 40) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(154, a * b);
+                    }
+                    // 7: mul -11, [rb+9], [rb+153]
+                    {
+                        let a: i64 = -11;
+                        let b: i64 = *vm.memory.entry((rb + 9) as usize).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 153) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 11, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 11;
+                }
+                11 => {
+                    // 11: out -20
+                    {
+                        stdout.push(-20);
+                        steps += 1;
+                    }
+                    // 13: eq [152], [159], [rb+155]
+                    {
+                        let a: i64 = *vm.memory.entry(152).or_insert(0);
+                        let b: i64 = *vm.memory.entry(159).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 155) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 17, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 17: lt [48], 3, [rb+156]
+                    {
+                        let a: i64 = *vm.memory.entry(48).or_insert(0);
+                        let b: i64 = 3;
+                        steps += 1;
+                        let addr = (rb + 156) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 21, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 21: jf [rb+11], 24
+                    {
+                        let cond: i64 = *vm.memory.entry((rb + 11) as usize).or_insert(0);
+                        let target: i64 = 24;
+                        ip = if cond == 0 { target as usize } else { 24 };
+                        steps += 1;
+                    }
+                }
+                24 => {
+                    // 24: jt [155], 27
+                    {
+                        let cond: i64 = *vm.memory.entry(155).or_insert(0);
+                        let target: i64 = 27;
+                        ip = if cond != 0 { target as usize } else { 27 };
+                        steps += 1;
+                    }
+                }
+                27 => {
+                    // 27: add [148], -1, [148]
+                    {
+                        let a: i64 = *vm.memory.entry(148).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(148, a + b);
+                    }
+                    // 31: jt [148], 11
+                    {
+                        let cond: i64 = *vm.memory.entry(148).or_insert(0);
+                        let target: i64 = 11;
+                        ip = if cond != 0 { target as usize } else { 34 };
+                        steps += 1;
+                    }
+                }
+                34 => {
+                    // 34: add [rb+52], [154], [153]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 52) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(154).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(153, a + b);
+                    }
+                    // 38: jt [rb+61], 147
+                    {
+                        let cond: i64 = *vm.memory.entry((rb + 61) as usize).or_insert(0);
+                        let target: i64 = 147;
+                        ip = if cond != 0 { target as usize } else { 41 };
+                        steps += 1;
+                    }
+                }
+                41 => {
+                    // 41: in [156]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(41);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(156, value);
+                    }
+                    // 43: arb 0
+                    {
+                        rb += 0;
+                        steps += 1;
+                    }
+                    ip = 45;
+                }
+                45 => {
+                    // 45: in [159]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(45);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(159, value);
+                    }
+                    // 47: add [rb+17], [157], [rb+155]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 17) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(157).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 155) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 51, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 51;
+                }
+                51 => {
+                    // 51: lt -20, [rb+55], [155]
+                    {
+                        let a: i64 = -20;
+                        let b: i64 = *vm.memory.entry((rb + 55) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(155, (a < b) as i64);
+                    }
+                    // 55: add [153], -8, [157]
+                    {
+                        let a: i64 = *vm.memory.entry(153).or_insert(0);
+                        let b: i64 = -8;
+                        steps += 1;
+                        vm.memory.insert(157, a + b);
+                    }
+                    // 59: eq [148], [rb+8], [157]
+                    {
+                        let a: i64 = *vm.memory.entry(148).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 8) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(157, (a == b) as i64);
+                    }
+                    // 63: out -13
+                    {
+                        stdout.push(-13);
+                        steps += 1;
+                    }
+                    // 65: out [rb+16]
+                    {
+                        stdout.push(*vm.memory.entry((rb + 16) as usize).or_insert(0));
+                        steps += 1;
+                    }
+                    // 67: add [149], -1, [149]
+                    {
+                        let a: i64 = *vm.memory.entry(149).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(149, a + b);
+                    }
+                    // 71: jt [149], 51
+                    {
+                        let cond: i64 = *vm.memory.entry(149).or_insert(0);
+                        let target: i64 = 51;
+                        ip = if cond != 0 { target as usize } else { 74 };
+                        steps += 1;
+                    }
+                }
+                74 => {
+                    // 74: jt [16], 83
+                    {
+                        let cond: i64 = *vm.memory.entry(16).or_insert(0);
+                        let target: i64 = 83;
+                        ip = if cond != 0 { target as usize } else { 77 };
+                        steps += 1;
+                    }
+                }
+                77 => {
+                    // 77: jf 18, 80
+                    {
+                        let cond: i64 = 18;
+                        let target: i64 = 80;
+                        ip = if cond == 0 { target as usize } else { 80 };
+                        steps += 1;
+                    }
+                }
+                80 => {
+                    // 80: jf 1, 83
+                    {
+                        let cond: i64 = 1;
+                        let target: i64 = 83;
+                        ip = if cond == 0 { target as usize } else { 83 };
+                        steps += 1;
+                    }
+                }
+                83 => {
+                    // 83: jf [rb+53], 90
+                    {
+                        let cond: i64 = *vm.memory.entry((rb + 53) as usize).or_insert(0);
+                        let target: i64 = 90;
+                        ip = if cond == 0 { target as usize } else { 86 };
+                        steps += 1;
+                    }
+                }
+                86 => {
+                    // 86: mul [157], 0, [157]
+                    {
+                        let a: i64 = *vm.memory.entry(157).or_insert(0);
+                        let b: i64 = 0;
+                        steps += 1;
+                        vm.memory.insert(157, a * b);
+                    }
+                    ip = 90;
+                }
+                90 => {
+                    // 90: mul [46], -15, [158]
+                    {
+                        let a: i64 = *vm.memory.entry(46).or_insert(0);
+                        let b: i64 = -15;
+                        steps += 1;
+                        vm.memory.insert(158, a * b);
+                    }
+                    // 94: add [150], -1, [150]
+                    {
+                        let a: i64 = *vm.memory.entry(150).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(150, a + b);
+                    }
+                    // 98: jt [150], 74
+                    {
+                        let cond: i64 = *vm.memory.entry(150).or_insert(0);
+                        let target: i64 = 74;
+                        ip = if cond != 0 { target as usize } else { 101 };
+                        steps += 1;
+                    }
+                }
+                101 => {
+                    // 101: add [rb+24], -4, [rb+155]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 24) as usize).or_insert(0);
+                        let b: i64 = -4;
+                        steps += 1;
+                        let addr = (rb + 155) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 105, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 105: out [149]
+                    {
+                        stdout.push(*vm.memory.entry(149).or_insert(0));
+                        steps += 1;
+                    }
+                    // 107: out -17
+                    {
+                        stdout.push(-17);
+                        steps += 1;
+                    }
+                    // 109: lt [154], [rb+26], [rb+159]
+                    {
+                        let a: i64 = *vm.memory.entry(154).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 26) as usize).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 159) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 113, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 113;
+                }
+                113 => {
+                    // 113: jf [50], 122
+                    {
+                        let cond: i64 = *vm.memory.entry(50).or_insert(0);
+                        let target: i64 = 122;
+                        ip = if cond == 0 { target as usize } else { 116 };
+                        steps += 1;
+                    }
+                }
+                116 => {
+                    // 116: arb 1
+                    {
+                        rb += 1;
+                        steps += 1;
+                    }
+                    ip = 118;
+                }
+                118 => {
+                    // 118: in [157]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(118);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(157, value);
+                    }
+                    ip = 120;
+                }
+                120 => {
+                    // 120: in [158]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(120);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(158, value);
+                    }
+                    ip = 122;
+                }
+                122 => {
+                    // 122: in [rb+157]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(122);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 157) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 124, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 124: eq 18, -1, [152]
+                    {
+                        let a: i64 = 18;
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(152, (a == b) as i64);
+                    }
+                    // 128: add [151], -1, [151]
+                    {
+                        let a: i64 = *vm.memory.entry(151).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(151, a + b);
+                    }
+                    // 132: jt [151], 113
+                    {
+                        let cond: i64 = *vm.memory.entry(151).or_insert(0);
+                        let target: i64 = 113;
+                        ip = if cond != 0 { target as usize } else { 135 };
+                        steps += 1;
+                    }
+                }
+                135 => {
+                    // 135: lt 9, -14, [158]
+                    {
+                        let a: i64 = 9;
+                        let b: i64 = -14;
+                        steps += 1;
+                        vm.memory.insert(158, (a < b) as i64);
+                    }
+                    // 139: eq [rb+29], [157], [154]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 29) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(157).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(154, (a == b) as i64);
+                    }
+                    // 143: eq 12, 5, [rb+152]
+                    {
+                        let a: i64 = 12;
+                        let b: i64 = 5;
+                        steps += 1;
+                        let addr = (rb + 152) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 147, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 147;
+                }
+                147 => {
+                    // 147: halt
+                    vm.set_ip(147);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_4 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 19] = [21001, 15, 19, 13, 20001, 25, 18, 12, 203, 15, 99, -1, -1, -10, -5, 2, -3, -8, 8];
+    const COMPILED: [bool; 19] = [true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: add [15], 19, [rb+13]
+                    {
+                        let a: i64 = *vm.memory.entry(15).or_insert(0);
+                        let b: i64 = 19;
+                        steps += 1;
+                        let addr = (rb + 13) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 4, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 4: add [25], [18], [rb+12]
+                    {
+                        let a: i64 = *vm.memory.entry(25).or_insert(0);
+                        let b: i64 = *vm.memory.entry(18).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 12) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 8, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 8;
+                }
+                8 => {
+                    // 8: in [rb+15]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(8);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 15) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 10, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 10: halt
+                    vm.set_ip(10);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_5 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 20] = [1007, 15, 7, 16, 20002, 19, 12, 16, 1106, 17, 11, 99, 8, 5, -2, 0, 6, 6, 7, -6];
+    const COMPILED: [bool; 20] = [true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: lt [15], 7, [16]
+                    {
+                        let a: i64 = *vm.memory.entry(15).or_insert(0);
+                        let b: i64 = 7;
+                        steps += 1;
+                        vm.memory.insert(16, (a < b) as i64);
+                    }
+                    // 4: mul [19], [12], [rb+16]
+                    {
+                        let a: i64 = *vm.memory.entry(19).or_insert(0);
+                        let b: i64 = *vm.memory.entry(12).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 16) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 8, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 8: jf 17, 11
+                    {
+                        let cond: i64 = 17;
+                        let target: i64 = 11;
+                        ip = if cond == 0 { target as usize } else { 11 };
+                        steps += 1;
+                    }
+                }
+                11 => {
+                    // 11: halt
+                    vm.set_ip(11);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_6 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 78] = [1105, -14, 45, 4, 9, 20007, 77, 41, 73, 109, 3, 1206, 51, 19, 109, 4, 1205, 17, 19, 202, 54, 72, 73, 1001, 69, -1, 69, 1005, 69, 9, 1202, 56, 8, 74, 204, 15, 109, 3, 1007, 73, 16, 73, 1105, -16, 53, 22102, 9, 19, 72, 20108, -13, 69, 76, 8, 75, 69, 74, 1101, 12, -20, 71, 1006, 72, 64, 1007, 69, 17, 77, 99, 2, -5, 5, 8, 8, 3, 9, -4, -8];
+    const COMPILED: [bool; 78] = [true, true, true, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: jt -14, 45
+                    {
+                        let cond: i64 = -14;
+                        let target: i64 = 45;
+                        ip = if cond != 0 { target as usize } else { 3 };
+                        steps += 1;
+                    }
+                }
+                45 => {
+                    // 45: mul 9, [rb+19], [rb+72]
+                    {
+                        let a: i64 = 9;
+                        let b: i64 = *vm.memory.entry((rb + 19) as usize).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 72) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 49, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 49: eq -13, [69], [rb+76]
+                    {
+                        let a: i64 = -13;
+                        let b: i64 = *vm.memory.entry(69).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 76) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 53, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 53: eq [75], [69], [74]
+                    {
+                        let a: i64 = *vm.memory.entry(75).or_insert(0);
+                        let b: i64 = *vm.memory.entry(69).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(74, (a == b) as i64);
+                    }
+                    // 57: add 12, -20, [71]
+                    {
+                        let a: i64 = 12;
+                        let b: i64 = -20;
+                        steps += 1;
+                        vm.memory.insert(71, a + b);
+                    }
+                    // 61: jf [72], 64
+                    {
+                        let cond: i64 = *vm.memory.entry(72).or_insert(0);
+                        let target: i64 = 64;
+                        ip = if cond == 0 { target as usize } else { 64 };
+                        steps += 1;
+                    }
+                }
+                64 => {
+                    // 64: lt [69], 17, [77]
+                    {
+                        let a: i64 = *vm.memory.entry(69).or_insert(0);
+                        let b: i64 = 17;
+                        steps += 1;
+                        vm.memory.insert(77, (a < b) as i64);
+                    }
+                    // 68: halt
+                    vm.set_ip(68);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_7 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 58] = [1006, 54, 42, 22001, 56, 17, 57, 21101, -13, -13, 51, 208, 9, 55, 55, 1006, 52, 22, 21007, 56, -11, 52, 109, 2, 2102, 9, 60, 50, 1001, 49, -1, 49, 1005, 49, 11, 20008, 29, 52, 53, 1005, 49, 48, 4, 51, 21008, 35, -20, 52, 99, 1, 6, -9, 0, -3, -8, 9, 0, 2];
+    const COMPILED: [bool; 58] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: jf [54], 42
+                    {
+                        let cond: i64 = *vm.memory.entry(54).or_insert(0);
+                        let target: i64 = 42;
+                        ip = if cond == 0 { target as usize } else { 3 };
+                        steps += 1;
+                    }
+                }
+                3 => {
+                    // 3: add [56], [rb+17], [rb+57]
+                    {
+                        let a: i64 = *vm.memory.entry(56).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 17) as usize).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 57) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 7, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 7: add -13, -13, [rb+51]
+                    {
+                        let a: i64 = -13;
+                        let b: i64 = -13;
+                        steps += 1;
+                        let addr = (rb + 51) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 11, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 11;
+                }
+                11 => {
+                    // 11: eq [rb+9], [55], [55]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 9) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(55).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(55, (a == b) as i64);
+                    }
+                    // 15: jf [52], 22
+                    {
+                        let cond: i64 = *vm.memory.entry(52).or_insert(0);
+                        let target: i64 = 22;
+                        ip = if cond == 0 { target as usize } else { 18 };
+                        steps += 1;
+                    }
+                }
+                18 => {
+                    // 18: lt [56], -11, [rb+52]
+                    {
+                        let a: i64 = *vm.memory.entry(56).or_insert(0);
+                        let b: i64 = -11;
+                        steps += 1;
+                        let addr = (rb + 52) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 22, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 22;
+                }
+                22 => {
+                    // 22: arb 2
+                    {
+                        rb += 2;
+                        steps += 1;
+                    }
+                    // 24: mul 9, [rb+60], [50]
+                    {
+                        let a: i64 = 9;
+                        let b: i64 = *vm.memory.entry((rb + 60) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(50, a * b);
+                    }
+                    // 28: add [49], -1, [49]
+                    {
+                        let a: i64 = *vm.memory.entry(49).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(49, a + b);
+                    }
+                    // 32: jt [49], 11
+                    {
+                        let cond: i64 = *vm.memory.entry(49).or_insert(0);
+                        let target: i64 = 11;
+                        ip = if cond != 0 { target as usize } else { 35 };
+                        steps += 1;
+                    }
+                }
+                35 => {
+                    // 35: eq [29], [52], [rb+53]
+                    {
+                        let a: i64 = *vm.memory.entry(29).or_insert(0);
+                        let b: i64 = *vm.memory.entry(52).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 53) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 39, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 39: jt [49], 48
+                    {
+                        let cond: i64 = *vm.memory.entry(49).or_insert(0);
+                        let target: i64 = 48;
+                        ip = if cond != 0 { target as usize } else { 42 };
+                        steps += 1;
+                    }
+                }
+                42 => {
+                    // 42: out [51]
+                    {
+                        stdout.push(*vm.memory.entry(51).or_insert(0));
+                        steps += 1;
+                    }
+                    // 44: eq [35], -20, [rb+52]
+                    {
+                        let a: i64 = *vm.memory.entry(35).or_insert(0);
+                        let b: i64 = -20;
+                        steps += 1;
+                        let addr = (rb + 52) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 48, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 48;
+                }
+                48 => {
+                    // 48: halt
+                    vm.set_ip(48);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_8 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 62] = [1206, 40, 15, 21007, 59, -8, 55, 21207, 18, -1, 55, 1, 60, 55, 58, 104, 4, 21008, 59, -2, 54, 1007, 31, 8, 60, 1108, -11, 9, 55, 21208, 16, -15, 57, 2007, 36, 20, 58, 109, 3, 2208, 34, -1, 56, 21207, 8, -3, 56, 1006, 60, 53, 1006, 25, 53, 99, 1, -5, -10, 3, -10, -8, -8, 2];
+    const COMPILED: [bool; 62] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: jf [rb+40], 15
+                    {
+                        let cond: i64 = *vm.memory.entry((rb + 40) as usize).or_insert(0);
+                        let target: i64 = 15;
+                        ip = if cond == 0 { target as usize } else { 3 };
+                        steps += 1;
+                    }
+                }
+                3 => {
+                    // 3: lt [59], -8, [rb+55]
+                    {
+                        let a: i64 = *vm.memory.entry(59).or_insert(0);
+                        let b: i64 = -8;
+                        steps += 1;
+                        let addr = (rb + 55) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 7, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 7: lt [rb+18], -1, [rb+55]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 18) as usize).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        let addr = (rb + 55) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 11, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 11: add [60], [55], [58]
+                    {
+                        let a: i64 = *vm.memory.entry(60).or_insert(0);
+                        let b: i64 = *vm.memory.entry(55).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(58, a + b);
+                    }
+                    ip = 15;
+                }
+                15 => {
+                    // 15: out 4
+                    {
+                        stdout.push(4);
+                        steps += 1;
+                    }
+                    // 17: eq [59], -2, [rb+54]
+                    {
+                        let a: i64 = *vm.memory.entry(59).or_insert(0);
+                        let b: i64 = -2;
+                        steps += 1;
+                        let addr = (rb + 54) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 21, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 21: lt [31], 8, [60]
+                    {
+                        let a: i64 = *vm.memory.entry(31).or_insert(0);
+                        let b: i64 = 8;
+                        steps += 1;
+                        vm.memory.insert(60, (a < b) as i64);
+                    }
+                    // 25: eq -11, 9, [55]
+                    {
+                        let a: i64 = -11;
+                        let b: i64 = 9;
+                        steps += 1;
+                        vm.memory.insert(55, (a == b) as i64);
+                    }
+                    // 29: eq [rb+16], -15, [rb+57]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 16) as usize).or_insert(0);
+                        let b: i64 = -15;
+                        steps += 1;
+                        let addr = (rb + 57) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 33, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 33: lt [36], [rb+20], [58]
+                    {
+                        let a: i64 = *vm.memory.entry(36).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 20) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(58, (a < b) as i64);
+                    }
+                    // 37: arb 3
+                    {
+                        rb += 3;
+                        steps += 1;
+                    }
+                    // 39: eq [rb+34], [rb-1], [56]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 34) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + -1) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(56, (a == b) as i64);
+                    }
+                    // 43: lt [rb+8], -3, [rb+56]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 8) as usize).or_insert(0);
+                        let b: i64 = -3;
+                        steps += 1;
+                        let addr = (rb + 56) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 47, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 47: jf [60], 53
+                    {
+                        let cond: i64 = *vm.memory.entry(60).or_insert(0);
+                        let target: i64 = 53;
+                        ip = if cond == 0 { target as usize } else { 50 };
+                        steps += 1;
+                    }
+                }
+                50 => {
+                    // 50: jf [25], 53
+                    {
+                        let cond: i64 = *vm.memory.entry(25).or_insert(0);
+                        let target: i64 = 53;
+                        ip = if cond == 0 { target as usize } else { 53 };
+                        steps += 1;
+                    }
+                }
+                53 => {
+                    // 53: halt
+                    vm.set_ip(53);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_9 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 128] = [107, 7, 127, 126, 3, 121, 2107, -11, 29, 122, 1005, 15, 50, 8, 10, 125, 124, 1106, -7, 84, 20002, 125, 124, 120, 22208, 48, 5, 127, 1006, 120, 40, 1006, 121, 34, 2, 120, 124, 124, 3, 127, 1001, 117, -1, 117, 1005, 117, 20, 1106, 17, 50, 1006, 121, 88, 21007, 26, -20, 127, 109, 4, 20102, -13, 127, 124, 1006, 125, 66, 1001, 118, -1, 118, 1005, 118, 59, 109, 2, 109, 4, 1001, 119, -1, 119, 1005, 119, 73, 2207, -2, 49, 126, 1006, 121, 105, 207, 47, 125, 121, 20208, 48, 127, 126, 21202, 23, -4, 123, 109, 2, 3, 123, 20002, 119, 51, 125, 1105, 10, 116, 203, 126, 99, 4, 4, 1, 2, -9, -10, 3, 6, 0, 5, 4];
+    const COMPILED: [bool; 128] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, true, false, false, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: lt 7, [127], [126]
+                    {
+                        let a: i64 = 7;
+                        let b: i64 = *vm.memory.entry(127).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(126, (a < b) as i64);
+                    }
+                    ip = 4;
+                }
+                4 => {
+                    // 4: in [121]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(4);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(121, value);
+                    }
+                    // 6: lt -11, [rb+29], [122]
+                    {
+                        let a: i64 = -11;
+                        let b: i64 = *vm.memory.entry((rb + 29) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(122, (a < b) as i64);
+                    }
+                    // 10: jt [15], 50
+                    {
+                        let cond: i64 = *vm.memory.entry(15).or_insert(0);
+                        let target: i64 = 50;
+                        ip = if cond != 0 { target as usize } else { 13 };
+                        steps += 1;
+                    }
+                }
+                13 => {
+                    // 13: eq [10], [125], [124]
+                    {
+                        let a: i64 = *vm.memory.entry(10).or_insert(0);
+                        let b: i64 = *vm.memory.entry(125).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(124, (a == b) as i64);
+                    }
+                    // 17: jf -7, 84
+                    {
+                        let cond: i64 = -7;
+                        let target: i64 = 84;
+                        ip = if cond == 0 { target as usize } else { 20 };
+                        steps += 1;
+                    }
+                }
+                20 => {
+                    // 20: mul [125], [124], [rb+120]
+                    {
+                        let a: i64 = *vm.memory.entry(125).or_insert(0);
+                        let b: i64 = *vm.memory.entry(124).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 120) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 24, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 24: eq [rb+48], [rb+5], [rb+127]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 48) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 5) as usize).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 127) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 28, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 28: jf [120], 40
+                    {
+                        let cond: i64 = *vm.memory.entry(120).or_insert(0);
+                        let target: i64 = 40;
+                        ip = if cond == 0 { target as usize } else { 31 };
+                        steps += 1;
+                    }
+                }
+                31 => {
+                    // 31: jf [121], 34
+                    {
+                        let cond: i64 = *vm.memory.entry(121).or_insert(0);
+                        let target: i64 = 34;
+                        ip = if cond == 0 { target as usize } else { 34 };
+                        steps += 1;
+                    }
+                }
+                34 => {
+                    // 34: mul [120], [124], [124]
+                    {
+                        let a: i64 = *vm.memory.entry(120).or_insert(0);
+                        let b: i64 = *vm.memory.entry(124).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(124, a * b);
+                    }
+                    ip = 38;
+                }
+                38 => {
+                    // 38: in [127]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(38);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(127, value);
+                    }
+                    ip = 40;
+                }
+                40 => {
+                    // 40: add [117], -1, [117]
+                    {
+                        let a: i64 = *vm.memory.entry(117).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(117, a + b);
+                    }
+                    // 44: jt [117], 20
+                    {
+                        let cond: i64 = *vm.memory.entry(117).or_insert(0);
+                        let target: i64 = 20;
+                        ip = if cond != 0 { target as usize } else { 47 };
+                        steps += 1;
+                    }
+                }
+                47 => {
+                    // 47: jf 17, 50
+                    {
+                        let cond: i64 = 17;
+                        let target: i64 = 50;
+                        ip = if cond == 0 { target as usize } else { 50 };
+                        steps += 1;
+                    }
+                }
+                50 => {
+                    // 50: jf [121], 88
+                    {
+                        let cond: i64 = *vm.memory.entry(121).or_insert(0);
+                        let target: i64 = 88;
+                        ip = if cond == 0 { target as usize } else { 53 };
+                        steps += 1;
+                    }
+                }
+                53 => {
+                    // 53: lt [26], -20, [rb+127]
+                    {
+                        let a: i64 = *vm.memory.entry(26).or_insert(0);
+                        let b: i64 = -20;
+                        steps += 1;
+                        let addr = (rb + 127) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 57, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 57: arb 4
+                    {
+                        rb += 4;
+                        steps += 1;
+                    }
+                    ip = 59;
+                }
+                59 => {
+                    // 59: mul -13, [127], [rb+124]
+                    {
+                        let a: i64 = -13;
+                        let b: i64 = *vm.memory.entry(127).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 124) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 63, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 63: jf [125], 66
+                    {
+                        let cond: i64 = *vm.memory.entry(125).or_insert(0);
+                        let target: i64 = 66;
+                        ip = if cond == 0 { target as usize } else { 66 };
+                        steps += 1;
+                    }
+                }
+                66 => {
+                    // 66: add [118], -1, [118]
+                    {
+                        let a: i64 = *vm.memory.entry(118).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(118, a + b);
+                    }
+                    // 70: jt [118], 59
+                    {
+                        let cond: i64 = *vm.memory.entry(118).or_insert(0);
+                        let target: i64 = 59;
+                        ip = if cond != 0 { target as usize } else { 73 };
+                        steps += 1;
+                    }
+                }
+                73 => {
+                    // 73: arb 2
+                    {
+                        rb += 2;
+                        steps += 1;
+                    }
+                    // 75: arb 4
+                    {
+                        rb += 4;
+                        steps += 1;
+                    }
+                    // 77: add [119], -1, [119]
+                    {
+                        let a: i64 = *vm.memory.entry(119).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(119, a + b);
+                    }
+                    // 81: jt [119], 73
+                    {
+                        let cond: i64 = *vm.memory.entry(119).or_insert(0);
+                        let target: i64 = 73;
+                        ip = if cond != 0 { target as usize } else { 84 };
+                        steps += 1;
+                    }
+                }
+                84 => {
+                    // 84: lt [rb-2], [rb+49], [126]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + -2) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 49) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(126, (a < b) as i64);
+                    }
+                    ip = 88;
+                }
+                88 => {
+                    // 88: jf [121], 105
+                    {
+                        let cond: i64 = *vm.memory.entry(121).or_insert(0);
+                        let target: i64 = 105;
+                        ip = if cond == 0 { target as usize } else { 91 };
+                        steps += 1;
+                    }
+                }
+                91 => {
+                    // 91: lt [rb+47], [125], [121]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 47) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(125).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(121, (a < b) as i64);
+                    }
+                    // 95: eq [rb+48], [127], [rb+126]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 48) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(127).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 126) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 99, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 99: mul [rb+23], -4, [rb+123]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 23) as usize).or_insert(0);
+                        let b: i64 = -4;
+                        steps += 1;
+                        let addr = (rb + 123) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 103, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 103: arb 2
+                    {
+                        rb += 2;
+                        steps += 1;
+                    }
+                    ip = 105;
+                }
+                105 => {
+                    // 105: in [123]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(105);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(123, value);
+                    }
+                    // 107: mul [119], [51], [rb+125]
+                    {
+                        let a: i64 = *vm.memory.entry(119).or_insert(0);
+                        let b: i64 = *vm.memory.entry(51).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 125) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 111, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 111: jt 10, 116
+                    {
+                        let cond: i64 = 10;
+                        let target: i64 = 116;
+                        ip = if cond != 0 { target as usize } else { 114 };
+                        steps += 1;
+                    }
+                }
+                116 => {
+                    // 116: halt
+                    vm.set_ip(116);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_10 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 49] = [3, 47, 1005, 2, 19, 108, -1, 61, 42, 1, 42, 25, 42, 2, 47, 36, 43, 203, 47, 104, 10, 109, 4, 22108, -2, 24, 45, 20207, 5, 31, 47, 4, 42, 1005, 38, 36, 20007, 42, 42, 48, 99, 6, 8, 0, 6, -2, -7, -6, -7];
+    const COMPILED: [bool; 49] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: in [47]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(0);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(47, value);
+                    }
+                    // 2: jt [2], 19
+                    {
+                        let cond: i64 = *vm.memory.entry(2).or_insert(0);
+                        let target: i64 = 19;
+                        ip = if cond != 0 { target as usize } else { 5 };
+                        steps += 1;
+                    }
+                }
+                5 => {
+                    // 5: eq -1, [61], [42]
+                    {
+                        let a: i64 = -1;
+                        let b: i64 = *vm.memory.entry(61).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(42, (a == b) as i64);
+                    }
+                    // 9: add [42], [25], [42]
+                    {
+                        let a: i64 = *vm.memory.entry(42).or_insert(0);
+                        let b: i64 = *vm.memory.entry(25).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(42, a + b);
+                    }
+                    // 13: mul [47], [36], [43]
+                    {
+                        let a: i64 = *vm.memory.entry(47).or_insert(0);
+                        let b: i64 = *vm.memory.entry(36).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(43, a * b);
+                    }
+                    ip = 17;
+                }
+                17 => {
+                    // 17: in [rb+47]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(17);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 47) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 19, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 19;
+                }
+                19 => {
+                    // 19: out 10
+                    {
+                        stdout.push(10);
+                        steps += 1;
+                    }
+                    // 21: arb 4
+                    {
+                        rb += 4;
+                        steps += 1;
+                    }
+                    // 23: eq -2, [rb+24], [rb+45]
+                    {
+                        let a: i64 = -2;
+                        let b: i64 = *vm.memory.entry((rb + 24) as usize).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 45) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 27, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 27: lt [rb+5], [31], [rb+47]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 5) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(31).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 47) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 31, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 31: out [42]
+                    {
+                        stdout.push(*vm.memory.entry(42).or_insert(0));
+                        steps += 1;
+                    }
+                    // 33: jt [38], 36
+                    {
+                        let cond: i64 = *vm.memory.entry(38).or_insert(0);
+                        let target: i64 = 36;
+                        ip = if cond != 0 { target as usize } else { 36 };
+                        steps += 1;
+                    }
+                }
+                36 => {
+                    // 36: lt [42], [42], [rb+48]
+                    {
+                        let a: i64 = *vm.memory.entry(42).or_insert(0);
+                        let b: i64 = *vm.memory.entry(42).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 48) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 40, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 40: halt
+                    vm.set_ip(40);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_11 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 24] = [109, 3, 1206, 9, 11, 4, 19, 1101, 15, -20, 16, 20002, 18, 20, 20, 99, 2, -7, 8, 2, -1, -3, 5, 4];
+    const COMPILED: [bool; 24] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: arb 3
+                    {
+                        rb += 3;
+                        steps += 1;
+                    }
+                    // 2: jf [rb+9], 11
+                    {
+                        let cond: i64 = *vm.memory.entry((rb + 9) as usize).or_insert(0);
+                        let target: i64 = 11;
+                        ip = if cond == 0 { target as usize } else { 5 };
+                        steps += 1;
+                    }
+                }
+                5 => {
+                    // 5: out [19]
+                    {
+                        stdout.push(*vm.memory.entry(19).or_insert(0));
+                        steps += 1;
+                    }
+                    // 7: add 15, -20, [16]
+                    {
+                        let a: i64 = 15;
+                        let b: i64 = -20;
+                        steps += 1;
+                        vm.memory.insert(16, a + b);
+                    }
+                    ip = 11;
+                }
+                11 => {
+                    // 11: mul [18], [20], [rb+20]
+                    {
+                        let a: i64 = *vm.memory.entry(18).or_insert(0);
+                        let b: i64 = *vm.memory.entry(20).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 20) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 15, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 15: halt
+                    vm.set_ip(15);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_12 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 138] = [1006, 137, 34, 20001, 3, 133, 132, 1006, 50, 10, 1001, 126, -1, 126, 1005, 126, 7, 1006, 15, 97, 101, -12, 126, 133, 1206, 49, 27, 1001, 127, -1, 127, 1005, 127, 24, 1008, 126, 2, 137, 108, -19, 137, 132, 109, 3, 22108, 3, 6, 134, 20001, 131, 136, 134, 2, 136, 137, 135, 1105, 4, 123, 20202, 3, 19, 132, 203, 135, 21108, -3, 2, 136, 109, 4, 1205, 48, 87, 21207, 38, -13, 131, 104, -14, 21102, 3, -8, 137, 1006, 17, 87, 1001, 128, -1, 128, 1005, 128, 69, 1006, 137, 123, 20102, -16, 6, 134, 1007, 131, -14, 130, 1005, 126, 116, 21001, 136, 6, 136, 109, 3, 109, 0, 1001, 129, -1, 129, 1005, 129, 97, 109, 3, 99, 2, 3, 2, 3, -5, 7, 4, -7, 0, -4, 8, 5];
+    const COMPILED: [bool; 138] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: jf [137], 34
+                    {
+                        let cond: i64 = *vm.memory.entry(137).or_insert(0);
+                        let target: i64 = 34;
+                        ip = if cond == 0 { target as usize } else { 3 };
+                        steps += 1;
+                    }
+                }
+                3 => {
+                    // 3: add [3], [133], [rb+132]
+                    {
+                        let a: i64 = *vm.memory.entry(3).or_insert(0);
+                        let b: i64 = *vm.memory.entry(133).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 132) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 7, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 7;
+                }
+                7 => {
+                    // 7: jf [50], 10
+                    {
+                        let cond: i64 = *vm.memory.entry(50).or_insert(0);
+                        let target: i64 = 10;
+                        ip = if cond == 0 { target as usize } else { 10 };
+                        steps += 1;
+                    }
+                }
+                10 => {
+                    // 10: add [126], -1, [126]
+                    {
+                        let a: i64 = *vm.memory.entry(126).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(126, a + b);
+                    }
+                    // 14: jt [126], 7
+                    {
+                        let cond: i64 = *vm.memory.entry(126).or_insert(0);
+                        let target: i64 = 7;
+                        ip = if cond != 0 { target as usize } else { 17 };
+                        steps += 1;
+                    }
+                }
+                17 => {
+                    // 17: jf [15], 97
+                    {
+                        let cond: i64 = *vm.memory.entry(15).or_insert(0);
+                        let target: i64 = 97;
+                        ip = if cond == 0 { target as usize } else { 20 };
+                        steps += 1;
+                    }
+                }
+                20 => {
+                    // 20: add -12, [126], [133]
+                    {
+                        let a: i64 = -12;
+                        let b: i64 = *vm.memory.entry(126).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(133, a + b);
+                    }
+                    ip = 24;
+                }
+                24 => {
+                    // 24: jf [rb+49], 27
+                    {
+                        let cond: i64 = *vm.memory.entry((rb + 49) as usize).or_insert(0);
+                        let target: i64 = 27;
+                        ip = if cond == 0 { target as usize } else { 27 };
+                        steps += 1;
+                    }
+                }
+                27 => {
+                    // 27: add [127], -1, [127]
+                    {
+                        let a: i64 = *vm.memory.entry(127).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(127, a + b);
+                    }
+                    // 31: jt [127], 24
+                    {
+                        let cond: i64 = *vm.memory.entry(127).or_insert(0);
+                        let target: i64 = 24;
+                        ip = if cond != 0 { target as usize } else { 34 };
+                        steps += 1;
+                    }
+                }
+                34 => {
+                    // 34: eq [126], 2, [137]
+                    {
+                        let a: i64 = *vm.memory.entry(126).or_insert(0);
+                        let b: i64 = 2;
+                        steps += 1;
+                        vm.memory.insert(137, (a == b) as i64);
+                    }
+                    // 38: eq -19, [137], [132]
+                    {
+                        let a: i64 = -19;
+                        let b: i64 = *vm.memory.entry(137).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(132, (a == b) as i64);
+                    }
+                    // 42: arb 3
+                    {
+                        rb += 3;
+                        steps += 1;
+                    }
+                    // 44: eq 3, [rb+6], [rb+134]
+                    {
+                        let a: i64 = 3;
+                        let b: i64 = *vm.memory.entry((rb + 6) as usize).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 134) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 48, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 48: add [131], [136], [rb+134]
+                    {
+                        let a: i64 = *vm.memory.entry(131).or_insert(0);
+                        let b: i64 = *vm.memory.entry(136).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 134) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 52, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 52: mul [136], [137], [135]
+                    {
+                        let a: i64 = *vm.memory.entry(136).or_insert(0);
+                        let b: i64 = *vm.memory.entry(137).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(135, a * b);
+                    }
+                    // 56: jt 4, 123
+                    {
+                        let cond: i64 = 4;
+                        let target: i64 = 123;
+                        ip = if cond != 0 { target as usize } else { 59 };
+                        steps += 1;
+                    }
+                }
+                97 => {
+                    // 97: mul -16, [6], [rb+134]
+                    {
+                        let a: i64 = -16;
+                        let b: i64 = *vm.memory.entry(6).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 134) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 101, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 101: lt [131], -14, [130]
+                    {
+                        let a: i64 = *vm.memory.entry(131).or_insert(0);
+                        let b: i64 = -14;
+                        steps += 1;
+                        vm.memory.insert(130, (a < b) as i64);
+                    }
+                    // 105: jt [126], 116
+                    {
+                        let cond: i64 = *vm.memory.entry(126).or_insert(0);
+                        let target: i64 = 116;
+                        ip = if cond != 0 { target as usize } else { 108 };
+                        steps += 1;
+                    }
+                }
+                108 => {
+                    // 108: add [136], 6, [rb+136]
+                    {
+                        let a: i64 = *vm.memory.entry(136).or_insert(0);
+                        let b: i64 = 6;
+                        steps += 1;
+                        let addr = (rb + 136) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 112, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 112: arb 3
+                    {
+                        rb += 3;
+                        steps += 1;
+                    }
+                    // 114: arb 0
+                    {
+                        rb += 0;
+                        steps += 1;
+                    }
+                    ip = 116;
+                }
+                116 => {
+                    // 116: add [129], -1, [129]
+                    {
+                        let a: i64 = *vm.memory.entry(129).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(129, a + b);
+                    }
+                    // 120: jt [129], 97
+                    {
+                        let cond: i64 = *vm.memory.entry(129).or_insert(0);
+                        let target: i64 = 97;
+                        ip = if cond != 0 { target as usize } else { 123 };
+                        steps += 1;
+                    }
+                }
+                123 => {
+                    // 123: arb 3
+                    {
+                        rb += 3;
+                        steps += 1;
+                    }
+                    // 125: halt
+                    vm.set_ip(125);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_13 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 49] = [109, 2, 109, 3, 3, 46, 2, 5, 48, 44, 104, 15, 20108, -3, 43, 48, 20208, 11, 44, 43, 109, 4, 22008, 42, 29, 45, 1001, 40, -1, 40, 1005, 40, 22, 3, 44, 108, -7, 45, 42, 99, 2, 9, 6, -3, -1, -4, -2, -7, -1];
+    const COMPILED: [bool; 49] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: arb 2
+                    {
+                        rb += 2;
+                        steps += 1;
+                    }
+                    // 2: arb 3
+                    {
+                        rb += 3;
+                        steps += 1;
+                    }
+                    ip = 4;
+                }
+                4 => {
+                    // 4: in [46]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(4);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(46, value);
+                    }
+                    // 6: mul [5], [48], [44]
+                    {
+                        let a: i64 = *vm.memory.entry(5).or_insert(0);
+                        let b: i64 = *vm.memory.entry(48).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(44, a * b);
+                    }
+                    // 10: out 15
+                    {
+                        stdout.push(15);
+                        steps += 1;
+                    }
+                    // 12: eq -3, [43], [rb+48]
+                    {
+                        let a: i64 = -3;
+                        let b: i64 = *vm.memory.entry(43).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 48) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 16, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 16: eq [rb+11], [44], [rb+43]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 11) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(44).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 43) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 20, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 20: arb 4
+                    {
+                        rb += 4;
+                        steps += 1;
+                    }
+                    ip = 22;
+                }
+                22 => {
+                    // 22: eq [42], [rb+29], [rb+45]
+                    {
+                        let a: i64 = *vm.memory.entry(42).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 29) as usize).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 45) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 26, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 26: add [40], -1, [40]
+                    {
+                        let a: i64 = *vm.memory.entry(40).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(40, a + b);
+                    }
+                    // 30: jt [40], 22
+                    {
+                        let cond: i64 = *vm.memory.entry(40).or_insert(0);
+                        let target: i64 = 22;
+                        ip = if cond != 0 { target as usize } else { 33 };
+                        steps += 1;
+                    }
+                }
+                33 => {
+                    // 33: in [44]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(33);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(44, value);
+                    }
+                    // 35: eq -7, [45], [42]
+                    {
+                        let a: i64 = -7;
+                        let b: i64 = *vm.memory.entry(45).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(42, (a == b) as i64);
+                    }
+                    // 39: halt
+                    vm.set_ip(39);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_14 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 70] = [1, 21, 66, 66, 203, 69, 1106, 17, 50, 1, 68, 19, 68, 109, 1, 1005, 59, 42, 20202, 55, 65, 68, 20101, 19, 63, 66, 203, 63, 21201, 39, 10, 63, 1006, 2, 35, 1001, 60, -1, 60, 1005, 60, 22, 4, 62, 201, 44, 56, 66, 204, 19, 109, 1, 1001, 61, -1, 61, 1005, 61, 50, 99, 3, 4, -4, -10, 4, -9, -3, -10, -1, -3];
+    const COMPILED: [bool; 70] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: add [21], [66], [66]
+                    {
+                        let a: i64 = *vm.memory.entry(21).or_insert(0);
+                        let b: i64 = *vm.memory.entry(66).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(66, a + b);
+                    }
+                    ip = 4;
+                }
+                4 => {
+                    // 4: in [rb+69]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(4);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 69) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 6, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 6: jf 17, 50
+                    {
+                        let cond: i64 = 17;
+                        let target: i64 = 50;
+                        ip = if cond == 0 { target as usize } else { 9 };
+                        steps += 1;
+                    }
+                }
+                9 => {
+                    // 9: add [68], [19], [68]
+                    {
+                        let a: i64 = *vm.memory.entry(68).or_insert(0);
+                        let b: i64 = *vm.memory.entry(19).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(68, a + b);
+                    }
+                    // 13: arb 1
+                    {
+                        rb += 1;
+                        steps += 1;
+                    }
+                    // 15: jt [59], 42
+                    {
+                        let cond: i64 = *vm.memory.entry(59).or_insert(0);
+                        let target: i64 = 42;
+                        ip = if cond != 0 { target as usize } else { 18 };
+                        steps += 1;
+                    }
+                }
+                18 => {
+                    // 18: mul [rb+55], [65], [rb+68]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 55) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(65).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 68) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 22, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 22;
+                }
+                22 => {
+                    // 22: add 19, [63], [rb+66]
+                    {
+                        let a: i64 = 19;
+                        let b: i64 = *vm.memory.entry(63).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 66) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 26, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 26;
+                }
+                26 => {
+                    // 26: in [rb+63]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(26);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 63) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 28, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 28: add [rb+39], 10, [rb+63]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 39) as usize).or_insert(0);
+                        let b: i64 = 10;
+                        steps += 1;
+                        let addr = (rb + 63) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 32, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 32: jf [2], 35
+                    {
+                        let cond: i64 = *vm.memory.entry(2).or_insert(0);
+                        let target: i64 = 35;
+                        ip = if cond == 0 { target as usize } else { 35 };
+                        steps += 1;
+                    }
+                }
+                35 => {
+                    // 35: add [60], -1, [60]
+                    {
+                        let a: i64 = *vm.memory.entry(60).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(60, a + b);
+                    }
+                    // 39: jt [60], 22
+                    {
+                        let cond: i64 = *vm.memory.entry(60).or_insert(0);
+                        let target: i64 = 22;
+                        ip = if cond != 0 { target as usize } else { 42 };
+                        steps += 1;
+                    }
+                }
+                42 => {
+                    // 42: out [62]
+                    {
+                        stdout.push(*vm.memory.entry(62).or_insert(0));
+                        steps += 1;
+                    }
+                    // 44: add [rb+44], [56], [66]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 44) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(56).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(66, a + b);
+                    }
+                    // 48: out [rb+19]
+                    {
+                        stdout.push(*vm.memory.entry((rb + 19) as usize).or_insert(0));
+                        steps += 1;
+                    }
+                    ip = 50;
+                }
+                50 => {
+                    // 50: arb 1
+                    {
+                        rb += 1;
+                        steps += 1;
+                    }
+                    // 52: add [61], -1, [61]
+                    {
+                        let a: i64 = *vm.memory.entry(61).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(61, a + b);
+                    }
+                    // 56: jt [61], 50
+                    {
+                        let cond: i64 = *vm.memory.entry(61).or_insert(0);
+                        let target: i64 = 50;
+                        ip = if cond != 0 { target as usize } else { 59 };
+                        steps += 1;
+                    }
+                }
+                59 => {
+                    // 59: halt
+                    vm.set_ip(59);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_15 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 51] = [4, 60, 4, 44, 21007, 58, -16, 45, 7, 43, 52, 43, 203, 48, 101, -16, 48, 43, 4, 43, 21002, 46, 5, 43, 1005, 50, 27, 1006, 44, 30, 1001, 42, -1, 42, 1005, 42, 12, 1108, 17, 13, 45, 99, 1, 2, 8, 1, 3, -6, 5, 9, 0];
+    const COMPILED: [bool; 51] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: out [60]
+                    {
+                        stdout.push(*vm.memory.entry(60).or_insert(0));
+                        steps += 1;
+                    }
+                    // 2: out [44]
+                    {
+                        stdout.push(*vm.memory.entry(44).or_insert(0));
+                        steps += 1;
+                    }
+                    // 4: lt [58], -16, [rb+45]
+                    {
+                        let a: i64 = *vm.memory.entry(58).or_insert(0);
+                        let b: i64 = -16;
+                        steps += 1;
+                        let addr = (rb + 45) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 8, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 8: lt [43], [52], [43]
+                    {
+                        let a: i64 = *vm.memory.entry(43).or_insert(0);
+                        let b: i64 = *vm.memory.entry(52).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(43, (a < b) as i64);
+                    }
+                    ip = 12;
+                }
+                12 => {
+                    // 12: in [rb+48]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(12);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 48) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 14, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 14: add -16, [48], [43]
+                    {
+                        let a: i64 = -16;
+                        let b: i64 = *vm.memory.entry(48).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(43, a + b);
+                    }
+                    // 18: out [43]
+                    {
+                        stdout.push(*vm.memory.entry(43).or_insert(0));
+                        steps += 1;
+                    }
+                    // 20: mul [46], 5, [rb+43]
+                    {
+                        let a: i64 = *vm.memory.entry(46).or_insert(0);
+                        let b: i64 = 5;
+                        steps += 1;
+                        let addr = (rb + 43) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 24, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 24: jt [50], 27
+                    {
+                        let cond: i64 = *vm.memory.entry(50).or_insert(0);
+                        let target: i64 = 27;
+                        ip = if cond != 0 { target as usize } else { 27 };
+                        steps += 1;
+                    }
+                }
+                27 => {
+                    // 27: jf [44], 30
+                    {
+                        let cond: i64 = *vm.memory.entry(44).or_insert(0);
+                        let target: i64 = 30;
+                        ip = if cond == 0 { target as usize } else { 30 };
+                        steps += 1;
+                    }
+                }
+                30 => {
+                    // 30: add [42], -1, [42]
+                    {
+                        let a: i64 = *vm.memory.entry(42).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(42, a + b);
+                    }
+                    // 34: jt [42], 12
+                    {
+                        let cond: i64 = *vm.memory.entry(42).or_insert(0);
+                        let target: i64 = 12;
+                        ip = if cond != 0 { target as usize } else { 37 };
+                        steps += 1;
+                    }
+                }
+                37 => {
+                    // 37: eq 17, 13, [45]
+                    {
+                        let a: i64 = 17;
+                        let b: i64 = 13;
+                        steps += 1;
+                        vm.memory.insert(45, (a == b) as i64);
+                    }
+                    // 41: halt
+                    vm.set_ip(41);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_16 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 55] = [21001, 55, 14, 53, 1205, 13, 41, 104, 11, 2, 13, 47, 54, 1105, -10, 35, 4, 48, 1001, 46, -1, 46, 1005, 46, 16, 1106, 13, 28, 1105, 5, 35, 101, 19, 48, 52, 1, 47, 54, 47, 203, 47, 21107, -2, 6, 52, 99, 1, 2, 5, -10, 2, 7, 4, 8, 7];
+    const COMPILED: [bool; 55] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: add [55], 14, [rb+53]
+                    {
+                        let a: i64 = *vm.memory.entry(55).or_insert(0);
+                        let b: i64 = 14;
+                        steps += 1;
+                        let addr = (rb + 53) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 4, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 4: jt [rb+13], 41
+                    {
+                        let cond: i64 = *vm.memory.entry((rb + 13) as usize).or_insert(0);
+                        let target: i64 = 41;
+                        ip = if cond != 0 { target as usize } else { 7 };
+                        steps += 1;
+                    }
+                }
+                7 => {
+                    // 7: out 11
+                    {
+                        stdout.push(11);
+                        steps += 1;
+                    }
+                    // 9: mul [13], [47], [54]
+                    {
+                        let a: i64 = *vm.memory.entry(13).or_insert(0);
+                        let b: i64 = *vm.memory.entry(47).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(54, a * b);
+                    }
+                    // 13: jt -10, 35
+                    {
+                        let cond: i64 = -10;
+                        let target: i64 = 35;
+                        ip = if cond != 0 { target as usize } else { 16 };
+                        steps += 1;
+                    }
+                }
+                35 => {
+                    // 35: add [47], [54], [47]
+                    {
+                        let a: i64 = *vm.memory.entry(47).or_insert(0);
+                        let b: i64 = *vm.memory.entry(54).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(47, a + b);
+                    }
+                    ip = 39;
+                }
+                39 => {
+                    // 39: in [rb+47]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(39);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 47) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 41, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 41;
+                }
+                41 => {
+                    // 41: lt -2, 6, [rb+52]
+                    {
+                        let a: i64 = -2;
+                        let b: i64 = 6;
+                        steps += 1;
+                        let addr = (rb + 52) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 45, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 45: halt
+                    vm.set_ip(45);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_17 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 179] = [101, -11, 173, 177, 207, 5, 173, 177, 1005, 176, 72, 20108, 9, 32, 173, 1007, 41, -1, 174, 21002, 174, 10, 173, 203, 172, 1001, 165, -1, 165, 1005, 165, 23, 20008, 26, 177, 172, 21108, -9, -7, 171, 1005, 178, 108, 1006, 165, 151, 109, 3, 7, 27, 175, 171, 1205, 9, 57, 3, 177, 21101, 13, 13, 175, 20008, 175, 173, 176, 1001, 166, -1, 166, 1005, 166, 48, 109, 4, 1205, 15, 151, 203, 174, 1007, 171, 15, 177, 22007, 62, 49, 177, 1102, 19, 6, 175, 21008, 174, 1, 178, 109, 3, 203, 177, 1001, 167, -1, 167, 1005, 167, 79, 4, 10, 109, 1, 1001, 167, 5, 175, 1001, 168, -1, 168, 1005, 168, 108, 109, 4, 1206, 57, 130, 101, 1, 60, 174, 20107, 11, 47, 174, 107, -16, 172, 171, 204, 55, 109, 3, 1001, 169, -1, 169, 1005, 169, 123, 203, 174, 1106, 13, 154, 1106, -11, 157, 1001, 170, -1, 170, 1005, 170, 154, 99, 1, 4, 1, 1, 4, 2, -6, -4, -3, -10, -2, -8, 5, 2];
+    const COMPILED: [bool; 179] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: add -11, [173], [177]
+                    {
+                        let a: i64 = -11;
+                        let b: i64 = *vm.memory.entry(173).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(177, a + b);
+                    }
+                    // 4: lt [rb+5], [173], [177]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 5) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(173).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(177, (a < b) as i64);
+                    }
+                    // 8: jt [176], 72
+                    {
+                        let cond: i64 = *vm.memory.entry(176).or_insert(0);
+                        let target: i64 = 72;
+                        ip = if cond != 0 { target as usize } else { 11 };
+                        steps += 1;
+                    }
+                }
+                11 => {
+                    // 11: eq 9, [32], [rb+173]
+                    {
+                        let a: i64 = 9;
+                        let b: i64 = *vm.memory.entry(32).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 173) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 15, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 15: lt [41], -1, [174]
+                    {
+                        let a: i64 = *vm.memory.entry(41).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(174, (a < b) as i64);
+                    }
+                    // 19: mul [174], 10, [rb+173]
+                    {
+                        let a: i64 = *vm.memory.entry(174).or_insert(0);
+                        let b: i64 = 10;
+                        steps += 1;
+                        let addr = (rb + 173) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 23, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 23;
+                }
+                23 => {
+                    // 23: in [rb+172]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(23);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 172) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 25, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 25: add [165], -1, [165]
+                    {
+                        let a: i64 = *vm.memory.entry(165).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(165, a + b);
+                    }
+                    // 29: jt [165], 23
+                    {
+                        let cond: i64 = *vm.memory.entry(165).or_insert(0);
+                        let target: i64 = 23;
+                        ip = if cond != 0 { target as usize } else { 32 };
+                        steps += 1;
+                    }
+                }
+                32 => {
+                    // 32: eq [26], [177], [rb+172]
+                    {
+                        let a: i64 = *vm.memory.entry(26).or_insert(0);
+                        let b: i64 = *vm.memory.entry(177).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 172) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 36, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 36: eq -9, -7, [rb+171]
+                    {
+                        let a: i64 = -9;
+                        let b: i64 = -7;
+                        steps += 1;
+                        let addr = (rb + 171) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 40, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 40: jt [178], 108
+                    {
+                        let cond: i64 = *vm.memory.entry(178).or_insert(0);
+                        let target: i64 = 108;
+                        ip = if cond != 0 { target as usize } else { 43 };
+                        steps += 1;
+                    }
+                }
+                43 => {
+                    // 43: jf [165], 151
+                    {
+                        let cond: i64 = *vm.memory.entry(165).or_insert(0);
+                        let target: i64 = 151;
+                        ip = if cond == 0 { target as usize } else { 46 };
+                        steps += 1;
+                    }
+                }
+                46 => {
+                    // 46: arb 3
+                    {
+                        rb += 3;
+                        steps += 1;
+                    }
+                    ip = 48;
+                }
+                48 => {
+                    // 48: lt [27], [175], [171]
+                    {
+                        let a: i64 = *vm.memory.entry(27).or_insert(0);
+                        let b: i64 = *vm.memory.entry(175).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(171, (a < b) as i64);
+                    }
+                    // 52: jt [rb+9], 57
+                    {
+                        let cond: i64 = *vm.memory.entry((rb + 9) as usize).or_insert(0);
+                        let target: i64 = 57;
+                        ip = if cond != 0 { target as usize } else { 55 };
+                        steps += 1;
+                    }
+                }
+                55 => {
+                    // 55: in [177]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(55);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(177, value);
+                    }
+                    ip = 57;
+                }
+                57 => {
+                    // 57: add 13, 13, [rb+175]
+                    {
+                        let a: i64 = 13;
+                        let b: i64 = 13;
+                        steps += 1;
+                        let addr = (rb + 175) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 61, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 61: eq [175], [173], [rb+176]
+                    {
+                        let a: i64 = *vm.memory.entry(175).or_insert(0);
+                        let b: i64 = *vm.memory.entry(173).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 176) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 65, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 65: add [166], -1, [166]
+                    {
+                        let a: i64 = *vm.memory.entry(166).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(166, a + b);
+                    }
+                    // 69: jt [166], 48
+                    {
+                        let cond: i64 = *vm.memory.entry(166).or_insert(0);
+                        let target: i64 = 48;
+                        ip = if cond != 0 { target as usize } else { 72 };
+                        steps += 1;
+                    }
+                }
+                72 => {
+                    // 72: arb 4
+                    {
+                        rb += 4;
+                        steps += 1;
+                    }
+                    // 74: jt [rb+15], 151
+                    {
+                        let cond: i64 = *vm.memory.entry((rb + 15) as usize).or_insert(0);
+                        let target: i64 = 151;
+                        ip = if cond != 0 { target as usize } else { 77 };
+                        steps += 1;
+                    }
+                }
+                77 => {
+                    // 77: in [rb+174]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(77);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 174) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 79, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 79;
+                }
+                79 => {
+                    // 79: lt [171], 15, [177]
+                    {
+                        let a: i64 = *vm.memory.entry(171).or_insert(0);
+                        let b: i64 = 15;
+                        steps += 1;
+                        vm.memory.insert(177, (a < b) as i64);
+                    }
+                    // 83: lt [62], [rb+49], [rb+177]
+                    {
+                        let a: i64 = *vm.memory.entry(62).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 49) as usize).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 177) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 87, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 87: mul 19, 6, [175]
+                    {
+                        let a: i64 = 19;
+                        let b: i64 = 6;
+                        steps += 1;
+                        vm.memory.insert(175, a * b);
+                    }
+                    // 91: eq [174], 1, [rb+178]
+                    {
+                        let a: i64 = *vm.memory.entry(174).or_insert(0);
+                        let b: i64 = 1;
+                        steps += 1;
+                        let addr = (rb + 178) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 95, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 95: arb 3
+                    {
+                        rb += 3;
+                        steps += 1;
+                    }
+                    ip = 97;
+                }
+                97 => {
+                    // 97: in [rb+177]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(97);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 177) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 99, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 99: add [167], -1, [167]
+                    {
+                        let a: i64 = *vm.memory.entry(167).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(167, a + b);
+                    }
+                    // 103: jt [167], 79
+                    {
+                        let cond: i64 = *vm.memory.entry(167).or_insert(0);
+                        let target: i64 = 79;
+                        ip = if cond != 0 { target as usize } else { 106 };
+                        steps += 1;
+                    }
+                }
+                106 => {
+                    // 106: out [10]
+                    {
+                        stdout.push(*vm.memory.entry(10).or_insert(0));
+                        steps += 1;
+                    }
+                    ip = 108;
+                }
+                108 => {
+                    // 108: arb 1
+                    {
+                        rb += 1;
+                        steps += 1;
+                    }
+                    // 110: add [167], 5, [175]
+                    {
+                        let a: i64 = *vm.memory.entry(167).or_insert(0);
+                        let b: i64 = 5;
+                        steps += 1;
+                        vm.memory.insert(175, a + b);
+                    }
+                    // 114: add [168], -1, [168]
+                    {
+                        let a: i64 = *vm.memory.entry(168).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(168, a + b);
+                    }
+                    // 118: jt [168], 108
+                    {
+                        let cond: i64 = *vm.memory.entry(168).or_insert(0);
+                        let target: i64 = 108;
+                        ip = if cond != 0 { target as usize } else { 121 };
+                        steps += 1;
+                    }
+                }
+                121 => {
+                    // 121: arb 4
+                    {
+                        rb += 4;
+                        steps += 1;
+                    }
+                    ip = 123;
+                }
+                123 => {
+                    // 123: jf [rb+57], 130
+                    {
+                        let cond: i64 = *vm.memory.entry((rb + 57) as usize).or_insert(0);
+                        let target: i64 = 130;
+                        ip = if cond == 0 { target as usize } else { 126 };
+                        steps += 1;
+                    }
+                }
+                126 => {
+                    // 126: add 1, [60], [174]
+                    {
+                        let a: i64 = 1;
+                        let b: i64 = *vm.memory.entry(60).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(174, a + b);
+                    }
+                    ip = 130;
+                }
+                130 => {
+                    // 130: lt 11, [47], [rb+174]
+                    {
+                        let a: i64 = 11;
+                        let b: i64 = *vm.memory.entry(47).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 174) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 134, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 134: lt -16, [172], [171]
+                    {
+                        let a: i64 = -16;
+                        let b: i64 = *vm.memory.entry(172).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(171, (a < b) as i64);
+                    }
+                    // 138: out [rb+55]
+                    {
+                        stdout.push(*vm.memory.entry((rb + 55) as usize).or_insert(0));
+                        steps += 1;
+                    }
+                    // 140: arb 3
+                    {
+                        rb += 3;
+                        steps += 1;
+                    }
+                    // 142: add [169], -1, [169]
+                    {
+                        let a: i64 = *vm.memory.entry(169).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(169, a + b);
+                    }
+                    // 146: jt [169], 123
+                    {
+                        let cond: i64 = *vm.memory.entry(169).or_insert(0);
+                        let target: i64 = 123;
+                        ip = if cond != 0 { target as usize } else { 149 };
+                        steps += 1;
+                    }
+                }
+                149 => {
+                    // 149: in [rb+174]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(149);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 174) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 151, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 151;
+                }
+                151 => {
+                    // 151: jf 13, 154
+                    {
+                        let cond: i64 = 13;
+                        let target: i64 = 154;
+                        ip = if cond == 0 { target as usize } else { 154 };
+                        steps += 1;
+                    }
+                }
+                154 => {
+                    // 154: jf -11, 157
+                    {
+                        let cond: i64 = -11;
+                        let target: i64 = 157;
+                        ip = if cond == 0 { target as usize } else { 157 };
+                        steps += 1;
+                    }
+                }
+                157 => {
+                    // 157: add [170], -1, [170]
+                    {
+                        let a: i64 = *vm.memory.entry(170).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(170, a + b);
+                    }
+                    // 161: jt [170], 154
+                    {
+                        let cond: i64 = *vm.memory.entry(170).or_insert(0);
+                        let target: i64 = 154;
+                        ip = if cond != 0 { target as usize } else { 164 };
+                        steps += 1;
+                    }
+                }
+                164 => {
+                    // 164: halt
+                    vm.set_ip(164);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_18 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 50] = [4, 49, 4, 45, 1006, 47, 7, 2, 11, 58, 45, 2008, 45, -2, 43, 109, 4, 22008, 29, 17, 48, 20107, 17, 19, 47, 1005, 42, 34, 1006, 42, 31, 1006, 43, 41, 1001, 22, -18, 48, 1206, -2, 41, 99, -2, 2, 5, -5, -9, 3, -4, 4];
+    const COMPILED: [bool; 50] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: out [49]
+                    {
+                        stdout.push(*vm.memory.entry(49).or_insert(0));
+                        steps += 1;
+                    }
+                    // 2: out [45]
+                    {
+                        stdout.push(*vm.memory.entry(45).or_insert(0));
+                        steps += 1;
+                    }
+                    // 4: jf [47], 7
+                    {
+                        let cond: i64 = *vm.memory.entry(47).or_insert(0);
+                        let target: i64 = 7;
+                        ip = if cond == 0 { target as usize } else { 7 };
+                        steps += 1;
+                    }
+                }
+                7 => {
+                    // 7: mul [11], [58], [45]
+                    {
+                        let a: i64 = *vm.memory.entry(11).or_insert(0);
+                        let b: i64 = *vm.memory.entry(58).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(45, a * b);
+                    }
+                    // 11: eq [45], [rb-2], [43]
+                    {
+                        let a: i64 = *vm.memory.entry(45).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + -2) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(43, (a == b) as i64);
+                    }
+                    // 15: arb 4
+                    {
+                        rb += 4;
+                        steps += 1;
+                    }
+                    // 17: eq [29], [rb+17], [rb+48]
+                    {
+                        let a: i64 = *vm.memory.entry(29).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 17) as usize).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 48) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 21, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 21: lt 17, [19], [rb+47]
+                    {
+                        let a: i64 = 17;
+                        let b: i64 = *vm.memory.entry(19).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 47) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 25, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 25: jt [42], 34
+                    {
+                        let cond: i64 = *vm.memory.entry(42).or_insert(0);
+                        let target: i64 = 34;
+                        ip = if cond != 0 { target as usize } else { 28 };
+                        steps += 1;
+                    }
+                }
+                28 => {
+                    // 28: jf [42], 31
+                    {
+                        let cond: i64 = *vm.memory.entry(42).or_insert(0);
+                        let target: i64 = 31;
+                        ip = if cond == 0 { target as usize } else { 31 };
+                        steps += 1;
+                    }
+                }
+                31 => {
+                    // 31: jf [43], 41
+                    {
+                        let cond: i64 = *vm.memory.entry(43).or_insert(0);
+                        let target: i64 = 41;
+                        ip = if cond == 0 { target as usize } else { 34 };
+                        steps += 1;
+                    }
+                }
+                34 => {
+                    // 34: add [22], -18, [48]
+                    {
+                        let a: i64 = *vm.memory.entry(22).or_insert(0);
+                        let b: i64 = -18;
+                        steps += 1;
+                        vm.memory.insert(48, a + b);
+                    }
+                    // 38: jf [rb-2], 41
+                    {
+                        let cond: i64 = *vm.memory.entry((rb + -2) as usize).or_insert(0);
+                        let target: i64 = 41;
+                        ip = if cond == 0 { target as usize } else { 41 };
+                        steps += 1;
+                    }
+                }
+                41 => {
+                    // 41: halt
+                    vm.set_ip(41);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_19 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 117] = [7, 54, 115, 112, 1006, 111, 22, 3, 114, 1005, 110, 83, 1007, 30, -7, 110, 108, 6, 110, 114, 109, 3, 22001, 112, 62, 112, 20008, 110, 112, 114, 109, 2, 108, -5, 110, 110, 22102, -14, 43, 114, 4, 116, 2101, -2, 35, 115, 8, 115, 7, 110, 109, 0, 20002, 45, 107, 110, 1005, 115, 59, 1001, 107, -1, 107, 1005, 107, 46, 2202, 13, 56, 110, 1106, -3, 77, 20002, 115, 114, 116, 109, 3, 20007, 111, 111, 112, 109, 0, 2002, 109, 56, 112, 1006, 116, 99, 1206, 61, 99, 20207, 7, 111, 115, 1001, 108, -1, 108, 1005, 108, 83, 99, 3, 2, -10, 2, 2, 9, -2, -10, 7, -3];
+    const COMPILED: [bool; 117] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: lt [54], [115], [112]
+                    {
+                        let a: i64 = *vm.memory.entry(54).or_insert(0);
+                        let b: i64 = *vm.memory.entry(115).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(112, (a < b) as i64);
+                    }
+                    // 4: jf [111], 22
+                    {
+                        let cond: i64 = *vm.memory.entry(111).or_insert(0);
+                        let target: i64 = 22;
+                        ip = if cond == 0 { target as usize } else { 7 };
+                        steps += 1;
+                    }
+                }
+                7 => {
+                    // 7: in [114]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(7);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(114, value);
+                    }
+                    // 9: jt [110], 83
+                    {
+                        let cond: i64 = *vm.memory.entry(110).or_insert(0);
+                        let target: i64 = 83;
+                        ip = if cond != 0 { target as usize } else { 12 };
+                        steps += 1;
+                    }
+                }
+                12 => {
+                    // 12: lt [30], -7, [110]
+                    {
+                        let a: i64 = *vm.memory.entry(30).or_insert(0);
+                        let b: i64 = -7;
+                        steps += 1;
+                        vm.memory.insert(110, (a < b) as i64);
+                    }
+                    // 16: eq 6, [110], [114]
+                    {
+                        let a: i64 = 6;
+                        let b: i64 = *vm.memory.entry(110).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(114, (a == b) as i64);
+                    }
+                    // 20: arb 3
+                    {
+                        rb += 3;
+                        steps += 1;
+                    }
+                    ip = 22;
+                }
+                22 => {
+                    // 22: add [112], [rb+62], [rb+112]
+                    {
+                        let a: i64 = *vm.memory.entry(112).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 62) as usize).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 112) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 26, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 26: eq [110], [112], [rb+114]
+                    {
+                        let a: i64 = *vm.memory.entry(110).or_insert(0);
+                        let b: i64 = *vm.memory.entry(112).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 114) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 30, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 30: arb 2
+                    {
+                        rb += 2;
+                        steps += 1;
+                    }
+                    // 32: eq -5, [110], [110]
+                    {
+                        let a: i64 = -5;
+                        let b: i64 = *vm.memory.entry(110).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(110, (a == b) as i64);
+                    }
+                    // 36: mul -14, [rb+43], [rb+114]
+                    {
+                        let a: i64 = -14;
+                        let b: i64 = *vm.memory.entry((rb + 43) as usize).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 114) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 40, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 40: out [116]
+                    {
+                        stdout.push(*vm.memory.entry(116).or_insert(0));
+                        steps += 1;
+                    }
+                    // 42: add -2, [rb+35], [115]
+                    {
+                        let a: i64 = -2;
+                        let b: i64 = *vm.memory.entry((rb + 35) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(115, a + b);
+                    }
+                    ip = 46;
+                }
+                46 => {
+                    // 46: eq [115], [7], [110]
+                    {
+                        let a: i64 = *vm.memory.entry(115).or_insert(0);
+                        let b: i64 = *vm.memory.entry(7).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(110, (a == b) as i64);
+                    }
+                    // 50: arb 0
+                    {
+                        rb += 0;
+                        steps += 1;
+                    }
+                    // 52: mul [45], [107], [rb+110]
+                    {
+                        let a: i64 = *vm.memory.entry(45).or_insert(0);
+                        let b: i64 = *vm.memory.entry(107).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 110) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 56, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 56: jt [115], 59
+                    {
+                        let cond: i64 = *vm.memory.entry(115).or_insert(0);
+                        let target: i64 = 59;
+                        ip = if cond != 0 { target as usize } else { 59 };
+                        steps += 1;
+                    }
+                }
+                59 => {
+                    // 59: add [107], -1, [107]
+                    {
+                        let a: i64 = *vm.memory.entry(107).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(107, a + b);
+                    }
+                    // 63: jt [107], 46
+                    {
+                        let cond: i64 = *vm.memory.entry(107).or_insert(0);
+                        let target: i64 = 46;
+                        ip = if cond != 0 { target as usize } else { 66 };
+                        steps += 1;
+                    }
+                }
+                66 => {
+                    // 66: mul [rb+13], [rb+56], [110]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 13) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 56) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(110, a * b);
+                    }
+                    // 70: jf -3, 77
+                    {
+                        let cond: i64 = -3;
+                        let target: i64 = 77;
+                        ip = if cond == 0 { target as usize } else { 73 };
+                        steps += 1;
+                    }
+                }
+                73 => {
+                    // 73: mul [115], [114], [rb+116]
+                    {
+                        let a: i64 = *vm.memory.entry(115).or_insert(0);
+                        let b: i64 = *vm.memory.entry(114).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 116) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 77, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 77: arb 3
+                    {
+                        rb += 3;
+                        steps += 1;
+                    }
+                    // 79: lt [111], [111], [rb+112]
+                    {
+                        let a: i64 = *vm.memory.entry(111).or_insert(0);
+                        let b: i64 = *vm.memory.entry(111).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 112) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 83, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 83;
+                }
+                83 => {
+                    // 83: arb 0
+                    {
+                        rb += 0;
+                        steps += 1;
+                    }
+                    // 85: mul [109], [rb+56], [112]
+                    {
+                        let a: i64 = *vm.memory.entry(109).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 56) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(112, a * b);
+                    }
+                    // 89: jf [116], 99
+                    {
+                        let cond: i64 = *vm.memory.entry(116).or_insert(0);
+                        let target: i64 = 99;
+                        ip = if cond == 0 { target as usize } else { 92 };
+                        steps += 1;
+                    }
+                }
+                92 => {
+                    // 92: jf [rb+61], 99
+                    {
+                        let cond: i64 = *vm.memory.entry((rb + 61) as usize).or_insert(0);
+                        let target: i64 = 99;
+                        ip = if cond == 0 { target as usize } else { 95 };
+                        steps += 1;
+                    }
+                }
+                95 => {
+                    // 95: lt [rb+7], [111], [rb+115]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 7) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(111).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 115) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 99, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 99;
+                }
+                99 => {
+                    // 99: add [108], -1, [108]
+                    {
+                        let a: i64 = *vm.memory.entry(108).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(108, a + b);
+                    }
+                    // 103: jt [108], 83
+                    {
+                        let cond: i64 = *vm.memory.entry(108).or_insert(0);
+                        let target: i64 = 83;
+                        ip = if cond != 0 { target as usize } else { 106 };
+                        steps += 1;
+                    }
+                }
+                106 => {
+                    // 106: halt
+                    vm.set_ip(106);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_20 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 141] = [20102, 7, 133, 135, 21001, 138, -18, 136, 1001, 128, -1, 128, 1005, 128, 4, 1001, 136, 8, 136, 104, 8, 1001, 129, -1, 129, 1005, 129, 19, 1005, 23, 39, 104, -16, 4, 136, 20201, 1, 137, 139, 203, 136, 1106, -4, 74, 20007, 133, 26, 140, 1205, -1, 59, 3, 139, 3, 136, 107, 10, 130, 138, 1001, 130, -1, 130, 1005, 130, 44, 20208, 63, 136, 139, 4, 129, 3, 134, 1005, 137, 77, 1001, 131, -1, 131, 1005, 131, 74, 1205, 23, 89, 109, 4, 3, 134, 1006, 136, 94, 203, 133, 3, 135, 1006, 135, 105, 21107, -11, -18, 139, 1001, 132, -1, 132, 1005, 132, 94, 108, 2, 135, 135, 20008, 29, 129, 134, 1105, 19, 123, 201, 49, 132, 135, 99, 1, 2, 4, 2, 2, -5, -6, -2, -5, 2, 1, 3, 3];
+    const COMPILED: [bool; 141] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: mul 7, [133], [rb+135]
+                    {
+                        let a: i64 = 7;
+                        let b: i64 = *vm.memory.entry(133).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 135) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 4, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 4;
+                }
+                4 => {
+                    // 4: add [138], -18, [rb+136]
+                    {
+                        let a: i64 = *vm.memory.entry(138).or_insert(0);
+                        let b: i64 = -18;
+                        steps += 1;
+                        let addr = (rb + 136) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 8, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 8: add [128], -1, [128]
+                    {
+                        let a: i64 = *vm.memory.entry(128).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(128, a + b);
+                    }
+                    // 12: jt [128], 4
+                    {
+                        let cond: i64 = *vm.memory.entry(128).or_insert(0);
+                        let target: i64 = 4;
+                        ip = if cond != 0 { target as usize } else { 15 };
+                        steps += 1;
+                    }
+                }
+                15 => {
+                    // 15: add [136], 8, [136]
+                    {
+                        let a: i64 = *vm.memory.entry(136).or_insert(0);
+                        let b: i64 = 8;
+                        steps += 1;
+                        vm.memory.insert(136, a + b);
+                    }
+                    ip = 19;
+                }
+                19 => {
+                    // 19: out 8
+                    {
+                        stdout.push(8);
+                        steps += 1;
+                    }
+                    // 21: add [129], -1, [129]
+                    {
+                        let a: i64 = *vm.memory.entry(129).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(129, a + b);
+                    }
+                    // 25: jt [129], 19
+                    {
+                        let cond: i64 = *vm.memory.entry(129).or_insert(0);
+                        let target: i64 = 19;
+                        ip = if cond != 0 { target as usize } else { 28 };
+                        steps += 1;
+                    }
+                }
+                28 => {
+                    // 28: jt [23], 39
+                    {
+                        let cond: i64 = *vm.memory.entry(23).or_insert(0);
+                        let target: i64 = 39;
+                        ip = if cond != 0 { target as usize } else { 31 };
+                        steps += 1;
+                    }
+                }
+                31 => {
+                    // 31: out -16
+                    {
+                        stdout.push(-16);
+                        steps += 1;
+                    }
+                    // 33: out [136]
+                    {
+                        stdout.push(*vm.memory.entry(136).or_insert(0));
+                        steps += 1;
+                    }
+                    // 35: add [rb+1], [137], [rb+139]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 1) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(137).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 139) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 39, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 39;
+                }
+                39 => {
+                    // 39: in [rb+136]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(39);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 136) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 41, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 41: jf -4, 74
+                    {
+                        let cond: i64 = -4;
+                        let target: i64 = 74;
+                        ip = if cond == 0 { target as usize } else { 44 };
+                        steps += 1;
+                    }
+                }
+                44 => {
+                    // 44: lt [133], [26], [rb+140]
+                    {
+                        let a: i64 = *vm.memory.entry(133).or_insert(0);
+                        let b: i64 = *vm.memory.entry(26).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 140) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 48, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 48: jt [rb-1], 59
+                    {
+                        let cond: i64 = *vm.memory.entry((rb + -1) as usize).or_insert(0);
+                        let target: i64 = 59;
+                        ip = if cond != 0 { target as usize } else { 51 };
+                        steps += 1;
+                    }
+                }
+                51 => {
+                    // 51: in [139]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(51);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(139, value);
+                    }
+                    ip = 53;
+                }
+                53 => {
+                    // 53: in [136]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(53);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(136, value);
+                    }
+                    // 55: lt 10, [130], [138]
+                    {
+                        let a: i64 = 10;
+                        let b: i64 = *vm.memory.entry(130).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(138, (a < b) as i64);
+                    }
+                    ip = 59;
+                }
+                59 => {
+                    // 59: add [130], -1, [130]
+                    {
+                        let a: i64 = *vm.memory.entry(130).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(130, a + b);
+                    }
+                    // 63: jt [130], 44
+                    {
+                        let cond: i64 = *vm.memory.entry(130).or_insert(0);
+                        let target: i64 = 44;
+                        ip = if cond != 0 { target as usize } else { 66 };
+                        steps += 1;
+                    }
+                }
+                66 => {
+                    // 66: eq [rb+63], [136], [rb+139]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 63) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(136).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 139) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 70, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 70: out [129]
+                    {
+                        stdout.push(*vm.memory.entry(129).or_insert(0));
+                        steps += 1;
+                    }
+                    ip = 72;
+                }
+                72 => {
+                    // 72: in [134]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(72);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(134, value);
+                    }
+                    ip = 74;
+                }
+                74 => {
+                    // 74: jt [137], 77
+                    {
+                        let cond: i64 = *vm.memory.entry(137).or_insert(0);
+                        let target: i64 = 77;
+                        ip = if cond != 0 { target as usize } else { 77 };
+                        steps += 1;
+                    }
+                }
+                77 => {
+                    // 77: add [131], -1, [131]
+                    {
+                        let a: i64 = *vm.memory.entry(131).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(131, a + b);
+                    }
+                    // 81: jt [131], 74
+                    {
+                        let cond: i64 = *vm.memory.entry(131).or_insert(0);
+                        let target: i64 = 74;
+                        ip = if cond != 0 { target as usize } else { 84 };
+                        steps += 1;
+                    }
+                }
+                84 => {
+                    // 84: jt [rb+23], 89
+                    {
+                        let cond: i64 = *vm.memory.entry((rb + 23) as usize).or_insert(0);
+                        let target: i64 = 89;
+                        ip = if cond != 0 { target as usize } else { 87 };
+                        steps += 1;
+                    }
+                }
+                87 => {
+                    // 87: arb 4
+                    {
+                        rb += 4;
+                        steps += 1;
+                    }
+                    ip = 89;
+                }
+                89 => {
+                    // 89: in [134]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(89);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(134, value);
+                    }
+                    // 91: jf [136], 94
+                    {
+                        let cond: i64 = *vm.memory.entry(136).or_insert(0);
+                        let target: i64 = 94;
+                        ip = if cond == 0 { target as usize } else { 94 };
+                        steps += 1;
+                    }
+                }
+                94 => {
+                    // 94: in [rb+133]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(94);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 133) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 96, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 96;
+                }
+                96 => {
+                    // 96: in [135]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(96);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(135, value);
+                    }
+                    // 98: jf [135], 105
+                    {
+                        let cond: i64 = *vm.memory.entry(135).or_insert(0);
+                        let target: i64 = 105;
+                        ip = if cond == 0 { target as usize } else { 101 };
+                        steps += 1;
+                    }
+                }
+                101 => {
+                    // 101: lt -11, -18, [rb+139]
+                    {
+                        let a: i64 = -11;
+                        let b: i64 = -18;
+                        steps += 1;
+                        let addr = (rb + 139) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 105, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 105;
+                }
+                105 => {
+                    // 105: add [132], -1, [132]
+                    {
+                        let a: i64 = *vm.memory.entry(132).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(132, a + b);
+                    }
+                    // 109: jt [132], 94
+                    {
+                        let cond: i64 = *vm.memory.entry(132).or_insert(0);
+                        let target: i64 = 94;
+                        ip = if cond != 0 { target as usize } else { 112 };
+                        steps += 1;
+                    }
+                }
+                112 => {
+                    // 112: eq 2, [135], [135]
+                    {
+                        let a: i64 = 2;
+                        let b: i64 = *vm.memory.entry(135).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(135, (a == b) as i64);
+                    }
+                    // 116: eq [29], [129], [rb+134]
+                    {
+                        let a: i64 = *vm.memory.entry(29).or_insert(0);
+                        let b: i64 = *vm.memory.entry(129).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 134) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 120, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 120: jt 19, 123
+                    {
+                        let cond: i64 = 19;
+                        let target: i64 = 123;
+                        ip = if cond != 0 { target as usize } else { 123 };
+                        steps += 1;
+                    }
+                }
+                123 => {
+                    // 123: add [rb+49], [132], [135]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 49) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(132).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(135, a + b);
+                    }
+                    // 127: halt
+                    vm.set_ip(127);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_21 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 160] = [1006, 154, 3, 1002, 153, -14, 154, 109, 2, 1006, 156, 18, 1108, 9, 1, 158, 109, 1, 20008, 146, 156, 153, 21202, 49, -12, 158, 1001, 146, -1, 146, 1005, 146, 7, 204, 18, 2001, 147, 46, 155, 7, 158, 154, 155, 203, 158, 101, 12, 158, 154, 1001, 147, -1, 147, 1005, 147, 33, 21201, 14, -4, 156, 1006, 155, 63, 1006, 154, 72, 20207, 1, 146, 154, 4, 153, 1001, 148, -1, 148, 1005, 148, 60, 1006, 152, 113, 20007, 158, 158, 157, 8, 156, 153, 156, 1106, -1, 93, 1106, 14, 96, 20001, 154, 21, 156, 1001, 149, -1, 149, 1005, 149, 90, 22007, 157, -1, 153, 203, 153, 2008, 155, 10, 158, 1001, 150, -1, 150, 1005, 150, 113, 2002, 156, 30, 156, 20001, 147, 157, 155, 1001, 151, -1, 151, 1005, 151, 124, 208, 24, 28, 157, 104, 13, 99, 1, 1, 1, 3, 1, 1, -1, 7, -10, 3, -1, -10, 7, -9];
+    const COMPILED: [bool; 160] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: jf [154], 3
+                    {
+                        let cond: i64 = *vm.memory.entry(154).or_insert(0);
+                        let target: i64 = 3;
+                        ip = if cond == 0 { target as usize } else { 3 };
+                        steps += 1;
+                    }
+                }
+                3 => {
+                    // 3: mul [153], -14, [154]
+                    {
+                        let a: i64 = *vm.memory.entry(153).or_insert(0);
+                        let b: i64 = -14;
+                        steps += 1;
+                        vm.memory.insert(154, a * b);
+                    }
+                    ip = 7;
+                }
+                7 => {
+                    // 7: arb 2
+                    {
+                        rb += 2;
+                        steps += 1;
+                    }
+                    // 9: jf [156], 18
+                    {
+                        let cond: i64 = *vm.memory.entry(156).or_insert(0);
+                        let target: i64 = 18;
+                        ip = if cond == 0 { target as usize } else { 12 };
+                        steps += 1;
+                    }
+                }
+                12 => {
+                    // 12: eq 9, 1, [158]
+                    {
+                        let a: i64 = 9;
+                        let b: i64 = 1;
+                        steps += 1;
+                        vm.memory.insert(158, (a == b) as i64);
+                    }
+                    // 16: arb 1
+                    {
+                        rb += 1;
+                        steps += 1;
+                    }
+                    ip = 18;
+                }
+                18 => {
+                    // 18: eq [146], [156], [rb+153]
+                    {
+                        let a: i64 = *vm.memory.entry(146).or_insert(0);
+                        let b: i64 = *vm.memory.entry(156).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 153) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 22, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 22: mul [rb+49], -12, [rb+158]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 49) as usize).or_insert(0);
+                        let b: i64 = -12;
+                        steps += 1;
+                        let addr = (rb + 158) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 26, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 26: add [146], -1, [146]
+                    {
+                        let a: i64 = *vm.memory.entry(146).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(146, a + b);
+                    }
+                    // 30: jt [146], 7
+                    {
+                        let cond: i64 = *vm.memory.entry(146).or_insert(0);
+                        let target: i64 = 7;
+                        ip = if cond != 0 { target as usize } else { 33 };
+                        steps += 1;
+                    }
+                }
+                33 => {
+                    // 33: out [rb+18]
+                    {
+                        stdout.push(*vm.memory.entry((rb + 18) as usize).or_insert(0));
+                        steps += 1;
+                    }
+                    // 35: add [147], [rb+46], [155]
+                    {
+                        let a: i64 = *vm.memory.entry(147).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 46) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(155, a + b);
+                    }
+                    // 39: lt [158], [154], [155]
+                    {
+                        let a: i64 = *vm.memory.entry(158).or_insert(0);
+                        let b: i64 = *vm.memory.entry(154).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(155, (a < b) as i64);
+                    }
+                    ip = 43;
+                }
+                43 => {
+                    // 43: in [rb+158]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(43);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 158) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 45, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 45: add 12, [158], [154]
+                    {
+                        let a: i64 = 12;
+                        let b: i64 = *vm.memory.entry(158).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(154, a + b);
+                    }
+                    // 49: add [147], -1, [147]
+                    {
+                        let a: i64 = *vm.memory.entry(147).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(147, a + b);
+                    }
+                    // 53: jt [147], 33
+                    {
+                        let cond: i64 = *vm.memory.entry(147).or_insert(0);
+                        let target: i64 = 33;
+                        ip = if cond != 0 { target as usize } else { 56 };
+                        steps += 1;
+                    }
+                }
+                56 => {
+                    // 56: add [rb+14], -4, [rb+156]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 14) as usize).or_insert(0);
+                        let b: i64 = -4;
+                        steps += 1;
+                        let addr = (rb + 156) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 60, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 60;
+                }
+                60 => {
+                    // 60: jf [155], 63
+                    {
+                        let cond: i64 = *vm.memory.entry(155).or_insert(0);
+                        let target: i64 = 63;
+                        ip = if cond == 0 { target as usize } else { 63 };
+                        steps += 1;
+                    }
+                }
+                63 => {
+                    // 63: jf [154], 72
+                    {
+                        let cond: i64 = *vm.memory.entry(154).or_insert(0);
+                        let target: i64 = 72;
+                        ip = if cond == 0 { target as usize } else { 66 };
+                        steps += 1;
+                    }
+                }
+                66 => {
+                    // 66: lt [rb+1], [146], [rb+154]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 1) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(146).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 154) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 70, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 70: out [153]
+                    {
+                        stdout.push(*vm.memory.entry(153).or_insert(0));
+                        steps += 1;
+                    }
+                    ip = 72;
+                }
+                72 => {
+                    // 72: add [148], -1, [148]
+                    {
+                        let a: i64 = *vm.memory.entry(148).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(148, a + b);
+                    }
+                    // 76: jt [148], 60
+                    {
+                        let cond: i64 = *vm.memory.entry(148).or_insert(0);
+                        let target: i64 = 60;
+                        ip = if cond != 0 { target as usize } else { 79 };
+                        steps += 1;
+                    }
+                }
+                79 => {
+                    // 79: jf [152], 113
+                    {
+                        let cond: i64 = *vm.memory.entry(152).or_insert(0);
+                        let target: i64 = 113;
+                        ip = if cond == 0 { target as usize } else { 82 };
+                        steps += 1;
+                    }
+                }
+                82 => {
+                    // 82: lt [158], [158], [rb+157]
+                    {
+                        let a: i64 = *vm.memory.entry(158).or_insert(0);
+                        let b: i64 = *vm.memory.entry(158).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 157) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 86, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 86: eq [156], [153], [156]
+                    {
+                        let a: i64 = *vm.memory.entry(156).or_insert(0);
+                        let b: i64 = *vm.memory.entry(153).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(156, (a == b) as i64);
+                    }
+                    ip = 90;
+                }
+                90 => {
+                    // 90: jf -1, 93
+                    {
+                        let cond: i64 = -1;
+                        let target: i64 = 93;
+                        ip = if cond == 0 { target as usize } else { 93 };
+                        steps += 1;
+                    }
+                }
+                93 => {
+                    // 93: jf 14, 96
+                    {
+                        let cond: i64 = 14;
+                        let target: i64 = 96;
+                        ip = if cond == 0 { target as usize } else { 96 };
+                        steps += 1;
+                    }
+                }
+                96 => {
+                    // 96: add [154], [21], [rb+156]
+                    {
+                        let a: i64 = *vm.memory.entry(154).or_insert(0);
+                        let b: i64 = *vm.memory.entry(21).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 156) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 100, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 100: add [149], -1, [149]
+                    {
+                        let a: i64 = *vm.memory.entry(149).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(149, a + b);
+                    }
+                    // 104: jt [149], 90
+                    {
+                        let cond: i64 = *vm.memory.entry(149).or_insert(0);
+                        let target: i64 = 90;
+                        ip = if cond != 0 { target as usize } else { 107 };
+                        steps += 1;
+                    }
+                }
+                107 => {
+                    // 107: lt [157], [rb-1], [rb+153]
+                    {
+                        let a: i64 = *vm.memory.entry(157).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + -1) as usize).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 153) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 111, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 111;
+                }
+                111 => {
+                    // 111: in [rb+153]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(111);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 153) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 113, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 113;
+                }
+                113 => {
+                    // 113: eq [155], [rb+10], [158]
+                    {
+                        let a: i64 = *vm.memory.entry(155).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 10) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(158, (a == b) as i64);
+                    }
+                    // 117: add [150], -1, [150]
+                    {
+                        let a: i64 = *vm.memory.entry(150).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(150, a + b);
+                    }
+                    // 121: jt [150], 113
+                    {
+                        let cond: i64 = *vm.memory.entry(150).or_insert(0);
+                        let target: i64 = 113;
+                        ip = if cond != 0 { target as usize } else { 124 };
+                        steps += 1;
+                    }
+                }
+                124 => {
+                    // 124: mul [156], [rb+30], [156]
+                    {
+                        let a: i64 = *vm.memory.entry(156).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 30) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(156, a * b);
+                    }
+                    // 128: add [147], [157], [rb+155]
+                    {
+                        let a: i64 = *vm.memory.entry(147).or_insert(0);
+                        let b: i64 = *vm.memory.entry(157).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 155) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 132, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 132: add [151], -1, [151]
+                    {
+                        let a: i64 = *vm.memory.entry(151).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(151, a + b);
+                    }
+                    // 136: jt [151], 124
+                    {
+                        let cond: i64 = *vm.memory.entry(151).or_insert(0);
+                        let target: i64 = 124;
+                        ip = if cond != 0 { target as usize } else { 139 };
+                        steps += 1;
+                    }
+                }
+                139 => {
+                    // 139: eq [rb+24], [28], [157]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 24) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(28).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(157, (a == b) as i64);
+                    }
+                    // 143: out 13
+                    {
+                        stdout.push(13);
+                        steps += 1;
+                    }
+                    // 145: halt
+                    vm.set_ip(145);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_22 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 22] = [104, 10, 203, 15, 109, 2, 204, 15, 1205, 17, 11, 109, 0, 99, 7, -8, 4, 2, 7, 6, 7, 6];
+    const COMPILED: [bool; 22] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: out 10
+                    {
+                        stdout.push(10);
+                        steps += 1;
+                    }
+                    ip = 2;
+                }
+                2 => {
+                    // 2: in [rb+15]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(2);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 15) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 4, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 4: arb 2
+                    {
+                        rb += 2;
+                        steps += 1;
+                    }
+                    // 6: out [rb+15]
+                    {
+                        stdout.push(*vm.memory.entry((rb + 15) as usize).or_insert(0));
+                        steps += 1;
+                    }
+                    // 8: jt [rb+17], 11
+                    {
+                        let cond: i64 = *vm.memory.entry((rb + 17) as usize).or_insert(0);
+                        let target: i64 = 11;
+                        ip = if cond != 0 { target as usize } else { 11 };
+                        steps += 1;
+                    }
+                }
+                11 => {
+                    // 11: arb 0
+                    {
+                        rb += 0;
+                        steps += 1;
+                    }
+                    // 13: halt
+                    vm.set_ip(13);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_23 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 39] = [109, 3, 21201, 47, 8, 36, 20108, -16, 30, 36, 21007, 31, 4, 32, 2, 53, 33, 36, 109, 1, 1001, 30, -1, 30, 1005, 30, 6, 203, 31, 99, 4, -7, -3, 7, 9, -2, 5, -9, 8];
+    const COMPILED: [bool; 39] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: arb 3
+                    {
+                        rb += 3;
+                        steps += 1;
+                    }
+                    // 2: add [rb+47], 8, [rb+36]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 47) as usize).or_insert(0);
+                        let b: i64 = 8;
+                        steps += 1;
+                        let addr = (rb + 36) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 6, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 6;
+                }
+                6 => {
+                    // 6: eq -16, [30], [rb+36]
+                    {
+                        let a: i64 = -16;
+                        let b: i64 = *vm.memory.entry(30).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 36) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 10, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 10: lt [31], 4, [rb+32]
+                    {
+                        let a: i64 = *vm.memory.entry(31).or_insert(0);
+                        let b: i64 = 4;
+                        steps += 1;
+                        let addr = (rb + 32) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 14, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 14: mul [53], [33], [36]
+                    {
+                        let a: i64 = *vm.memory.entry(53).or_insert(0);
+                        let b: i64 = *vm.memory.entry(33).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(36, a * b);
+                    }
+                    // 18: arb 1
+                    {
+                        rb += 1;
+                        steps += 1;
+                    }
+                    // 20: add [30], -1, [30]
+                    {
+                        let a: i64 = *vm.memory.entry(30).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(30, a + b);
+                    }
+                    // 24: jt [30], 6
+                    {
+                        let cond: i64 = *vm.memory.entry(30).or_insert(0);
+                        let target: i64 = 6;
+                        ip = if cond != 0 { target as usize } else { 27 };
+                        steps += 1;
+                    }
+                }
+                27 => {
+                    // 27: in [rb+31]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(27);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 31) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 29, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 29: halt
+                    vm.set_ip(29);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_24 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 96] = [203, 89, 1005, 12, 7, 4, 90, 104, 6, 109, 2, 1208, 57, 12, 88, 2002, 85, 49, 89, 22007, 88, 16, 94, 1005, 85, 26, 1001, 85, -1, 85, 1005, 85, 11, 104, 9, 7, 29, 27, 94, 20102, 9, 90, 92, 7, 95, 93, 95, 21102, -5, -9, 94, 1005, 35, 54, 1001, 86, -1, 86, 1005, 86, 39, 22002, 94, 60, 91, 1005, 93, 71, 1205, 12, 71, 109, 2, 20108, 3, 91, 94, 1001, 87, -1, 87, 1005, 87, 71, 99, 1, 1, 1, 3, -5, -10, -6, -1, -1, -7, 0];
+    const COMPILED: [bool; 96] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: in [rb+89]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(0);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 89) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 2, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 2: jt [12], 7
+                    {
+                        let cond: i64 = *vm.memory.entry(12).or_insert(0);
+                        let target: i64 = 7;
+                        ip = if cond != 0 { target as usize } else { 5 };
+                        steps += 1;
+                    }
+                }
+                5 => {
+                    // 5: out [90]
+                    {
+                        stdout.push(*vm.memory.entry(90).or_insert(0));
+                        steps += 1;
+                    }
+                    ip = 7;
+                }
+                7 => {
+                    // 7: out 6
+                    {
+                        stdout.push(6);
+                        steps += 1;
+                    }
+                    // 9: arb 2
+                    {
+                        rb += 2;
+                        steps += 1;
+                    }
+                    ip = 11;
+                }
+                11 => {
+                    // 11: eq [rb+57], 12, [88]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 57) as usize).or_insert(0);
+                        let b: i64 = 12;
+                        steps += 1;
+                        vm.memory.insert(88, (a == b) as i64);
+                    }
+                    // 15: mul [85], [rb+49], [89]
+                    {
+                        let a: i64 = *vm.memory.entry(85).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 49) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(89, a * b);
+                    }
+                    // 19: lt [88], [rb+16], [rb+94]
+                    {
+                        let a: i64 = *vm.memory.entry(88).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 16) as usize).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 94) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 23, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 23: jt [85], 26
+                    {
+                        let cond: i64 = *vm.memory.entry(85).or_insert(0);
+                        let target: i64 = 26;
+                        ip = if cond != 0 { target as usize } else { 26 };
+                        steps += 1;
+                    }
+                }
+                26 => {
+                    // 26: add [85], -1, [85]
+                    {
+                        let a: i64 = *vm.memory.entry(85).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(85, a + b);
+                    }
+                    // 30: jt [85], 11
+                    {
+                        let cond: i64 = *vm.memory.entry(85).or_insert(0);
+                        let target: i64 = 11;
+                        ip = if cond != 0 { target as usize } else { 33 };
+                        steps += 1;
+                    }
+                }
+                33 => {
+                    // 33: out 9
+                    {
+                        stdout.push(9);
+                        steps += 1;
+                    }
+                    // 35: lt [29], [27], [94]
+                    {
+                        let a: i64 = *vm.memory.entry(29).or_insert(0);
+                        let b: i64 = *vm.memory.entry(27).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(94, (a < b) as i64);
+                    }
+                    ip = 39;
+                }
+                39 => {
+                    // 39: mul 9, [90], [rb+92]
+                    {
+                        let a: i64 = 9;
+                        let b: i64 = *vm.memory.entry(90).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 92) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 43, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 43: lt [95], [93], [95]
+                    {
+                        let a: i64 = *vm.memory.entry(95).or_insert(0);
+                        let b: i64 = *vm.memory.entry(93).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(95, (a < b) as i64);
+                    }
+                    // 47: mul -5, -9, [rb+94]
+                    {
+                        let a: i64 = -5;
+                        let b: i64 = -9;
+                        steps += 1;
+                        let addr = (rb + 94) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 51, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 51: jt [35], 54
+                    {
+                        let cond: i64 = *vm.memory.entry(35).or_insert(0);
+                        let target: i64 = 54;
+                        ip = if cond != 0 { target as usize } else { 54 };
+                        steps += 1;
+                    }
+                }
+                54 => {
+                    // 54: add [86], -1, [86]
+                    {
+                        let a: i64 = *vm.memory.entry(86).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(86, a + b);
+                    }
+                    // 58: jt [86], 39
+                    {
+                        let cond: i64 = *vm.memory.entry(86).or_insert(0);
+                        let target: i64 = 39;
+                        ip = if cond != 0 { target as usize } else { 61 };
+                        steps += 1;
+                    }
+                }
+                61 => {
+                    // 61: mul [94], [rb+60], [rb+91]
+                    {
+                        let a: i64 = *vm.memory.entry(94).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 60) as usize).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 91) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 65, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 65: jt [93], 71
+                    {
+                        let cond: i64 = *vm.memory.entry(93).or_insert(0);
+                        let target: i64 = 71;
+                        ip = if cond != 0 { target as usize } else { 68 };
+                        steps += 1;
+                    }
+                }
+                68 => {
+                    // 68: jt [rb+12], 71
+                    {
+                        let cond: i64 = *vm.memory.entry((rb + 12) as usize).or_insert(0);
+                        let target: i64 = 71;
+                        ip = if cond != 0 { target as usize } else { 71 };
+                        steps += 1;
+                    }
+                }
+                71 => {
+                    // 71: arb 2
+                    {
+                        rb += 2;
+                        steps += 1;
+                    }
+                    // 73: eq 3, [91], [rb+94]
+                    {
+                        let a: i64 = 3;
+                        let b: i64 = *vm.memory.entry(91).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 94) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 77, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 77: add [87], -1, [87]
+                    {
+                        let a: i64 = *vm.memory.entry(87).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(87, a + b);
+                    }
+                    // 81: jt [87], 71
+                    {
+                        let cond: i64 = *vm.memory.entry(87).or_insert(0);
+                        let target: i64 = 71;
+                        ip = if cond != 0 { target as usize } else { 84 };
+                        steps += 1;
+                    }
+                }
+                84 => {
+                    // 84: halt
+                    vm.set_ip(84);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_25 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 112] = [1006, 106, 24, 20007, 107, 108, 108, 203, 111, 1005, 101, 12, 1006, 108, 15, 1001, 101, -1, 101, 1005, 101, 3, 3, 104, 1106, -13, 34, 8, 106, 105, 105, 1106, -8, 40, 21102, 16, -8, 110, 3, 106, 1001, 102, -1, 102, 1005, 102, 24, 109, 0, 1206, 31, 52, 1006, 101, 59, 20201, 7, 111, 105, 7, 103, 101, 110, 21107, -8, -19, 110, 1001, 103, -1, 103, 1005, 103, 49, 101, -7, 108, 105, 20002, 9, 103, 106, 1107, -19, -19, 110, 20008, 15, 41, 111, 3, 110, 1002, 46, 12, 109, 20008, 110, 104, 108, 99, 2, 2, 3, -4, -4, 7, -8, -10, 1, 5, 6];
+    const COMPILED: [bool; 112] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: jf [106], 24
+                    {
+                        let cond: i64 = *vm.memory.entry(106).or_insert(0);
+                        let target: i64 = 24;
+                        ip = if cond == 0 { target as usize } else { 3 };
+                        steps += 1;
+                    }
+                }
+                3 => {
+                    // 3: lt [107], [108], [rb+108]
+                    {
+                        let a: i64 = *vm.memory.entry(107).or_insert(0);
+                        let b: i64 = *vm.memory.entry(108).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 108) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 7, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 7;
+                }
+                7 => {
+                    // 7: in [rb+111]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(7);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 111) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 9, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 9: jt [101], 12
+                    {
+                        let cond: i64 = *vm.memory.entry(101).or_insert(0);
+                        let target: i64 = 12;
+                        ip = if cond != 0 { target as usize } else { 12 };
+                        steps += 1;
+                    }
+                }
+                12 => {
+                    // 12: jf [108], 15
+                    {
+                        let cond: i64 = *vm.memory.entry(108).or_insert(0);
+                        let target: i64 = 15;
+                        ip = if cond == 0 { target as usize } else { 15 };
+                        steps += 1;
+                    }
+                }
+                15 => {
+                    // 15: add [101], -1, [101]
+                    {
+                        let a: i64 = *vm.memory.entry(101).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(101, a + b);
+                    }
+                    // 19: jt [101], 3
+                    {
+                        let cond: i64 = *vm.memory.entry(101).or_insert(0);
+                        let target: i64 = 3;
+                        ip = if cond != 0 { target as usize } else { 22 };
+                        steps += 1;
+                    }
+                }
+                22 => {
+                    // 22: in [104]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(22);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(104, value);
+                    }
+                    ip = 24;
+                }
+                24 => {
+                    // 24: jf -13, 34
+                    {
+                        let cond: i64 = -13;
+                        let target: i64 = 34;
+                        ip = if cond == 0 { target as usize } else { 27 };
+                        steps += 1;
+                    }
+                }
+                27 => {
+                    // 27: eq [106], [105], [105]
+                    {
+                        let a: i64 = *vm.memory.entry(106).or_insert(0);
+                        let b: i64 = *vm.memory.entry(105).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(105, (a == b) as i64);
+                    }
+                    // 31: jf -8, 40
+                    {
+                        let cond: i64 = -8;
+                        let target: i64 = 40;
+                        ip = if cond == 0 { target as usize } else { 34 };
+                        steps += 1;
+                    }
+                }
+                34 => {
+                    // 34: mul 16, -8, [rb+110]
+                    {
+                        let a: i64 = 16;
+                        let b: i64 = -8;
+                        steps += 1;
+                        let addr = (rb + 110) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 38, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 38;
+                }
+                38 => {
+                    // 38: in [106]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(38);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(106, value);
+                    }
+                    // 40: add [102], -1, [102]
+                    {
+                        let a: i64 = *vm.memory.entry(102).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(102, a + b);
+                    }
+                    // 44: jt [102], 24
+                    {
+                        let cond: i64 = *vm.memory.entry(102).or_insert(0);
+                        let target: i64 = 24;
+                        ip = if cond != 0 { target as usize } else { 47 };
+                        steps += 1;
+                    }
+                }
+                47 => {
+                    // 47: arb 0
+                    {
+                        rb += 0;
+                        steps += 1;
+                    }
+                    ip = 49;
+                }
+                49 => {
+                    // 49: jf [rb+31], 52
+                    {
+                        let cond: i64 = *vm.memory.entry((rb + 31) as usize).or_insert(0);
+                        let target: i64 = 52;
+                        ip = if cond == 0 { target as usize } else { 52 };
+                        steps += 1;
+                    }
+                }
+                52 => {
+                    // 52: jf [101], 59
+                    {
+                        let cond: i64 = *vm.memory.entry(101).or_insert(0);
+                        let target: i64 = 59;
+                        ip = if cond == 0 { target as usize } else { 55 };
+                        steps += 1;
+                    }
+                }
+                55 => {
+                    // 55: add [rb+7], [111], [rb+105]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 7) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(111).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 105) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 59, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 59;
+                }
+                59 => {
+                    // 59: lt [103], [101], [110]
+                    {
+                        let a: i64 = *vm.memory.entry(103).or_insert(0);
+                        let b: i64 = *vm.memory.entry(101).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(110, (a < b) as i64);
+                    }
+                    // 63: lt -8, -19, [rb+110]
+                    {
+                        let a: i64 = -8;
+                        let b: i64 = -19;
+                        steps += 1;
+                        let addr = (rb + 110) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 67, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 67: add [103], -1, [103]
+                    {
+                        let a: i64 = *vm.memory.entry(103).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(103, a + b);
+                    }
+                    // 71: jt [103], 49
+                    {
+                        let cond: i64 = *vm.memory.entry(103).or_insert(0);
+                        let target: i64 = 49;
+                        ip = if cond != 0 { target as usize } else { 74 };
+                        steps += 1;
+                    }
+                }
+                74 => {
+                    // 74: add -7, [108], [105]
+                    {
+                        let a: i64 = -7;
+                        let b: i64 = *vm.memory.entry(108).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(105, a + b);
+                    }
+                    // 78: mul [9], [103], [rb+106]
+                    {
+                        let a: i64 = *vm.memory.entry(9).or_insert(0);
+                        let b: i64 = *vm.memory.entry(103).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 106) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 82, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 82: lt -19, -19, [110]
+                    {
+                        let a: i64 = -19;
+                        let b: i64 = -19;
+                        steps += 1;
+                        vm.memory.insert(110, (a < b) as i64);
+                    }
+                    // 86: eq [15], [41], [rb+111]
+                    {
+                        let a: i64 = *vm.memory.entry(15).or_insert(0);
+                        let b: i64 = *vm.memory.entry(41).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 111) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 90, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 90;
+                }
+                90 => {
+                    // 90: in [110]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(90);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(110, value);
+                    }
+                    // 92: mul [46], 12, [109]
+                    {
+                        let a: i64 = *vm.memory.entry(46).or_insert(0);
+                        let b: i64 = 12;
+                        steps += 1;
+                        vm.memory.insert(109, a * b);
+                    }
+                    // 96: eq [110], [104], [rb+108]
+                    {
+                        let a: i64 = *vm.memory.entry(110).or_insert(0);
+                        let b: i64 = *vm.memory.entry(104).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 108) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 100, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 100: halt
+                    vm.set_ip(100);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_26 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 40] = [203, 35, 4, 39, 22008, 34, 45, 38, 1, 37, 38, 33, 2107, 19, 30, 39, 1106, 4, 19, 8, 31, 31, 38, 1001, 31, -1, 31, 1005, 31, 12, 99, 4, 0, 3, 6, 5, 0, 0, -5, -10];
+    const COMPILED: [bool; 40] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: in [rb+35]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(0);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 35) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 2, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 2: out [39]
+                    {
+                        stdout.push(*vm.memory.entry(39).or_insert(0));
+                        steps += 1;
+                    }
+                    // 4: eq [34], [rb+45], [rb+38]
+                    {
+                        let a: i64 = *vm.memory.entry(34).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 45) as usize).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 38) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 8, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 8: add [37], [38], [33]
+                    {
+                        let a: i64 = *vm.memory.entry(37).or_insert(0);
+                        let b: i64 = *vm.memory.entry(38).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(33, a + b);
+                    }
+                    ip = 12;
+                }
+                12 => {
+                    // 12: lt 19, [rb+30], [39]
+                    {
+                        let a: i64 = 19;
+                        let b: i64 = *vm.memory.entry((rb + 30) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(39, (a < b) as i64);
+                    }
+                    // 16: jf 4, 19
+                    {
+                        let cond: i64 = 4;
+                        let target: i64 = 19;
+                        ip = if cond == 0 { target as usize } else { 19 };
+                        steps += 1;
+                    }
+                }
+                19 => {
+                    // 19: eq [31], [31], [38]
+                    {
+                        let a: i64 = *vm.memory.entry(31).or_insert(0);
+                        let b: i64 = *vm.memory.entry(31).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(38, (a == b) as i64);
+                    }
+                    // 23: add [31], -1, [31]
+                    {
+                        let a: i64 = *vm.memory.entry(31).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(31, a + b);
+                    }
+                    // 27: jt [31], 12
+                    {
+                        let cond: i64 = *vm.memory.entry(31).or_insert(0);
+                        let target: i64 = 12;
+                        ip = if cond != 0 { target as usize } else { 30 };
+                        steps += 1;
+                    }
+                }
+                30 => {
+                    // 30: halt
+                    vm.set_ip(30);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_27 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 59] = [1206, 51, 3, 2002, 52, 39, 55, 1, 6, 55, 53, 7, 49, 55, 51, 1001, 49, -1, 49, 1005, 49, 7, 20101, -7, 28, 58, 1208, 61, -17, 58, 1205, 50, 39, 203, 55, 21001, 57, -3, 52, 203, 51, 1001, 50, -1, 50, 1005, 50, 22, 99, 2, 4, 9, 0, -6, -1, 4, -5, -2, 7];
+    const COMPILED: [bool; 59] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: jf [rb+51], 3
+                    {
+                        let cond: i64 = *vm.memory.entry((rb + 51) as usize).or_insert(0);
+                        let target: i64 = 3;
+                        ip = if cond == 0 { target as usize } else { 3 };
+                        steps += 1;
+                    }
+                }
+                3 => {
+                    // 3: mul [52], [rb+39], [55]
+                    {
+                        let a: i64 = *vm.memory.entry(52).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 39) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(55, a * b);
+                    }
+                    ip = 7;
+                }
+                7 => {
+                    // 7: add [6], [55], [53]
+                    {
+                        let a: i64 = *vm.memory.entry(6).or_insert(0);
+                        let b: i64 = *vm.memory.entry(55).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(53, a + b);
+                    }
+                    // 11: lt [49], [55], [51]
+                    {
+                        let a: i64 = *vm.memory.entry(49).or_insert(0);
+                        let b: i64 = *vm.memory.entry(55).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(51, (a < b) as i64);
+                    }
+                    // 15: add [49], -1, [49]
+                    {
+                        let a: i64 = *vm.memory.entry(49).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(49, a + b);
+                    }
+                    // 19: jt [49], 7
+                    {
+                        let cond: i64 = *vm.memory.entry(49).or_insert(0);
+                        let target: i64 = 7;
+                        ip = if cond != 0 { target as usize } else { 22 };
+                        steps += 1;
+                    }
+                }
+                22 => {
+                    // 22: add -7, [28], [rb+58]
+                    {
+                        let a: i64 = -7;
+                        let b: i64 = *vm.memory.entry(28).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 58) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 26, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 26: eq [rb+61], -17, [58]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 61) as usize).or_insert(0);
+                        let b: i64 = -17;
+                        steps += 1;
+                        vm.memory.insert(58, (a == b) as i64);
+                    }
+                    // 30: jt [rb+50], 39
+                    {
+                        let cond: i64 = *vm.memory.entry((rb + 50) as usize).or_insert(0);
+                        let target: i64 = 39;
+                        ip = if cond != 0 { target as usize } else { 33 };
+                        steps += 1;
+                    }
+                }
+                33 => {
+                    // 33: in [rb+55]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(33);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 55) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 35, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 35: add [57], -3, [rb+52]
+                    {
+                        let a: i64 = *vm.memory.entry(57).or_insert(0);
+                        let b: i64 = -3;
+                        steps += 1;
+                        let addr = (rb + 52) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 39, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 39;
+                }
+                39 => {
+                    // 39: in [rb+51]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(39);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 51) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 41, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 41: add [50], -1, [50]
+                    {
+                        let a: i64 = *vm.memory.entry(50).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(50, a + b);
+                    }
+                    // 45: jt [50], 22
+                    {
+                        let cond: i64 = *vm.memory.entry(50).or_insert(0);
+                        let target: i64 = 22;
+                        ip = if cond != 0 { target as usize } else { 48 };
+                        steps += 1;
+                    }
+                }
+                48 => {
+                    // 48: halt
+                    vm.set_ip(48);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_28 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 147] = [109, 4, 20108, -5, 140, 143, 2, 145, 21, 143, 22007, 146, 53, 145, 1001, 134, -1, 134, 1005, 134, 10, 21101, -3, -5, 140, 20201, 63, 139, 139, 1001, 135, -1, 135, 1005, 135, 25, 1108, 12, -11, 140, 1006, 19, 46, 1005, 143, 101, 20007, 14, 146, 141, 204, 7, 1006, 144, 58, 1105, 8, 58, 1001, 136, -1, 136, 1005, 136, 52, 21108, 0, 2, 141, 4, 139, 204, 50, 1008, 136, 17, 142, 1107, -9, -15, 142, 1106, -20, 94, 203, 145, 20007, 141, 33, 139, 20107, 8, 140, 143, 1001, 137, -1, 137, 1005, 137, 81, 3, 143, 109, 3, 109, 4, 2101, -14, 11, 140, 22008, 136, 22, 143, 2101, 18, 59, 139, 1001, 138, -1, 138, 1005, 138, 101, 1006, 139, 133, 101, 4, 141, 144, 99, 1, 1, 2, 4, 3, 0, 1, 0, 0, 2, -7, 5, 4];
+    const COMPILED: [bool; 147] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: arb 4
+                    {
+                        rb += 4;
+                        steps += 1;
+                    }
+                    // 2: eq -5, [140], [rb+143]
+                    {
+                        let a: i64 = -5;
+                        let b: i64 = *vm.memory.entry(140).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 143) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 6, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 6: mul [145], [21], [143]
+                    {
+                        let a: i64 = *vm.memory.entry(145).or_insert(0);
+                        let b: i64 = *vm.memory.entry(21).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(143, a * b);
+                    }
+                    ip = 10;
+                }
+                10 => {
+                    // 10: lt [146], [rb+53], [rb+145]
+                    {
+                        let a: i64 = *vm.memory.entry(146).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 53) as usize).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 145) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 14, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 14: add [134], -1, [134]
+                    {
+                        let a: i64 = *vm.memory.entry(134).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(134, a + b);
+                    }
+                    // 18: jt [134], 10
+                    {
+                        let cond: i64 = *vm.memory.entry(134).or_insert(0);
+                        let target: i64 = 10;
+                        ip = if cond != 0 { target as usize } else { 21 };
+                        steps += 1;
+                    }
+                }
+                21 => {
+                    // 21: add -3, -5, [rb+140]
+                    {
+                        let a: i64 = -3;
+                        let b: i64 = -5;
+                        steps += 1;
+                        let addr = (rb + 140) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 25, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 25;
+                }
+                25 => {
+                    // 25: add [rb+63], [139], [rb+139]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 63) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(139).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 139) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 29, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 29: add [135], -1, [135]
+                    {
+                        let a: i64 = *vm.memory.entry(135).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(135, a + b);
+                    }
+                    // 33: jt [135], 25
+                    {
+                        let cond: i64 = *vm.memory.entry(135).or_insert(0);
+                        let target: i64 = 25;
+                        ip = if cond != 0 { target as usize } else { 36 };
+                        steps += 1;
+                    }
+                }
+                36 => {
+                    // 36: eq 12, -11, [140]
+                    {
+                        let a: i64 = 12;
+                        let b: i64 = -11;
+                        steps += 1;
+                        vm.memory.insert(140, (a == b) as i64);
+                    }
+                    // 40: jf [19], 46
+                    {
+                        let cond: i64 = *vm.memory.entry(19).or_insert(0);
+                        let target: i64 = 46;
+                        ip = if cond == 0 { target as usize } else { 43 };
+                        steps += 1;
+                    }
+                }
+                43 => {
+                    // 43: jt [143], 101
+                    {
+                        let cond: i64 = *vm.memory.entry(143).or_insert(0);
+                        let target: i64 = 101;
+                        ip = if cond != 0 { target as usize } else { 46 };
+                        steps += 1;
+                    }
+                }
+                46 => {
+                    // 46: lt [14], [146], [rb+141]
+                    {
+                        let a: i64 = *vm.memory.entry(14).or_insert(0);
+                        let b: i64 = *vm.memory.entry(146).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 141) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 50, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 50: out [rb+7]
+                    {
+                        stdout.push(*vm.memory.entry((rb + 7) as usize).or_insert(0));
+                        steps += 1;
+                    }
+                    ip = 52;
+                }
+                52 => {
+                    // 52: jf [144], 58
+                    {
+                        let cond: i64 = *vm.memory.entry(144).or_insert(0);
+                        let target: i64 = 58;
+                        ip = if cond == 0 { target as usize } else { 55 };
+                        steps += 1;
+                    }
+                }
+                55 => {
+                    // 55: jt 8, 58
+                    {
+                        let cond: i64 = 8;
+                        let target: i64 = 58;
+                        ip = if cond != 0 { target as usize } else { 58 };
+                        steps += 1;
+                    }
+                }
+                58 => {
+                    // 58: add [136], -1, [136]
+                    {
+                        let a: i64 = *vm.memory.entry(136).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(136, a + b);
+                    }
+                    // 62: jt [136], 52
+                    {
+                        let cond: i64 = *vm.memory.entry(136).or_insert(0);
+                        let target: i64 = 52;
+                        ip = if cond != 0 { target as usize } else { 65 };
+                        steps += 1;
+                    }
+                }
+                65 => {
+                    // 65: eq 0, 2, [rb+141]
+                    {
+                        let a: i64 = 0;
+                        let b: i64 = 2;
+                        steps += 1;
+                        let addr = (rb + 141) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 69, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 69: out [139]
+                    {
+                        stdout.push(*vm.memory.entry(139).or_insert(0));
+                        steps += 1;
+                    }
+                    // 71: out [rb+50]
+                    {
+                        stdout.push(*vm.memory.entry((rb + 50) as usize).or_insert(0));
+                        steps += 1;
+                    }
+                    // 73: eq [136], 17, [142]
+                    {
+                        let a: i64 = *vm.memory.entry(136).or_insert(0);
+                        let b: i64 = 17;
+                        steps += 1;
+                        vm.memory.insert(142, (a == b) as i64);
+                    }
+                    // 77: lt -9, -15, [142]
+                    {
+                        let a: i64 = -9;
+                        let b: i64 = -15;
+                        steps += 1;
+                        vm.memory.insert(142, (a < b) as i64);
+                    }
+                    ip = 81;
+                }
+                81 => {
+                    // 81: jf -20, 94
+                    {
+                        let cond: i64 = -20;
+                        let target: i64 = 94;
+                        ip = if cond == 0 { target as usize } else { 84 };
+                        steps += 1;
+                    }
+                }
+                84 => {
+                    // 84: in [rb+145]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(84);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 145) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 86, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 86: lt [141], [33], [rb+139]
+                    {
+                        let a: i64 = *vm.memory.entry(141).or_insert(0);
+                        let b: i64 = *vm.memory.entry(33).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 139) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 90, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 90: lt 8, [140], [rb+143]
+                    {
+                        let a: i64 = 8;
+                        let b: i64 = *vm.memory.entry(140).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 143) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 94, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 94: add [137], -1, [137]
+                    {
+                        let a: i64 = *vm.memory.entry(137).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(137, a + b);
+                    }
+                    // 98: jt [137], 81
+                    {
+                        let cond: i64 = *vm.memory.entry(137).or_insert(0);
+                        let target: i64 = 81;
+                        ip = if cond != 0 { target as usize } else { 101 };
+                        steps += 1;
+                    }
+                }
+                101 => {
+                    // 101: in [143]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(101);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(143, value);
+                    }
+                    // 103: arb 3
+                    {
+                        rb += 3;
+                        steps += 1;
+                    }
+                    // 105: arb 4
+                    {
+                        rb += 4;
+                        steps += 1;
+                    }
+                    // 107: add -14, [rb+11], [140]
+                    {
+                        let a: i64 = -14;
+                        let b: i64 = *vm.memory.entry((rb + 11) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(140, a + b);
+                    }
+                    // 111: eq [136], [rb+22], [rb+143]
+                    {
+                        let a: i64 = *vm.memory.entry(136).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 22) as usize).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 143) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 115, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 115: add 18, [rb+59], [139]
+                    {
+                        let a: i64 = 18;
+                        let b: i64 = *vm.memory.entry((rb + 59) as usize).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(139, a + b);
+                    }
+                    // 119: add [138], -1, [138]
+                    {
+                        let a: i64 = *vm.memory.entry(138).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(138, a + b);
+                    }
+                    // 123: jt [138], 101
+                    {
+                        let cond: i64 = *vm.memory.entry(138).or_insert(0);
+                        let target: i64 = 101;
+                        ip = if cond != 0 { target as usize } else { 126 };
+                        steps += 1;
+                    }
+                }
+                126 => {
+                    // 126: jf [139], 133
+                    {
+                        let cond: i64 = *vm.memory.entry(139).or_insert(0);
+                        let target: i64 = 133;
+                        ip = if cond == 0 { target as usize } else { 129 };
+                        steps += 1;
+                    }
+                }
+                129 => {
+                    // 129: add 4, [141], [144]
+                    {
+                        let a: i64 = 4;
+                        let b: i64 = *vm.memory.entry(141).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(144, a + b);
+                    }
+                    ip = 133;
+                }
+                133 => {
+                    // 133: halt
+                    vm.set_ip(133);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_29 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 44] = [203, 41, 203, 42, 4, 51, 204, 6, 104, -19, 1106, 18, 17, 1008, 38, -11, 36, 109, 1, 102, 2, 11, 39, 109, 2, 3, 40, 1001, 35, -1, 35, 1005, 35, 19, 99, 2, 1, -7, 6, -3, -5, -2, -9, 1];
+    const COMPILED: [bool; 44] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: in [rb+41]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(0);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 41) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 2, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 2;
+                }
+                2 => {
+                    // 2: in [rb+42]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(2);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        let addr = (rb + 42) as usize;
+                        vm.memory.insert(addr, value);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 4, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 4: out [51]
+                    {
+                        stdout.push(*vm.memory.entry(51).or_insert(0));
+                        steps += 1;
+                    }
+                    // 6: out [rb+6]
+                    {
+                        stdout.push(*vm.memory.entry((rb + 6) as usize).or_insert(0));
+                        steps += 1;
+                    }
+                    // 8: out -19
+                    {
+                        stdout.push(-19);
+                        steps += 1;
+                    }
+                    // 10: jf 18, 17
+                    {
+                        let cond: i64 = 18;
+                        let target: i64 = 17;
+                        ip = if cond == 0 { target as usize } else { 13 };
+                        steps += 1;
+                    }
+                }
+                13 => {
+                    // 13: eq [38], -11, [36]
+                    {
+                        let a: i64 = *vm.memory.entry(38).or_insert(0);
+                        let b: i64 = -11;
+                        steps += 1;
+                        vm.memory.insert(36, (a == b) as i64);
+                    }
+                    // 17: arb 1
+                    {
+                        rb += 1;
+                        steps += 1;
+                    }
+                    ip = 19;
+                }
+                19 => {
+                    // 19: mul 2, [11], [39]
+                    {
+                        let a: i64 = 2;
+                        let b: i64 = *vm.memory.entry(11).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(39, a * b);
+                    }
+                    // 23: arb 2
+                    {
+                        rb += 2;
+                        steps += 1;
+                    }
+                    ip = 25;
+                }
+                25 => {
+                    // 25: in [40]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(25);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(40, value);
+                    }
+                    // 27: add [35], -1, [35]
+                    {
+                        let a: i64 = *vm.memory.entry(35).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(35, a + b);
+                    }
+                    // 31: jt [35], 19
+                    {
+                        let cond: i64 = *vm.memory.entry(35).or_insert(0);
+                        let target: i64 = 19;
+                        ip = if cond != 0 { target as usize } else { 34 };
+                        steps += 1;
+                    }
+                }
+                34 => {
+                    // 34: halt
+                    vm.set_ip(34);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_30 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 36] = [101, -11, 33, 32, 3, 31, 20202, 40, 27, 32, 1005, 54, 13, 1001, 27, -1, 27, 1005, 27, 0, 208, -1, 27, 32, 4, 35, 99, 2, 2, 0, -5, 8, 8, 7, -1, -10];
+    const COMPILED: [bool; 36] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: add -11, [33], [32]
+                    {
+                        let a: i64 = -11;
+                        let b: i64 = *vm.memory.entry(33).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(32, a + b);
+                    }
+                    ip = 4;
+                }
+                4 => {
+                    // 4: in [31]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(4);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(31, value);
+                    }
+                    // 6: mul [rb+40], [27], [rb+32]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 40) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(27).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 32) as usize;
+                        vm.memory.insert(addr, a * b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 10, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 10: jt [54], 13
+                    {
+                        let cond: i64 = *vm.memory.entry(54).or_insert(0);
+                        let target: i64 = 13;
+                        ip = if cond != 0 { target as usize } else { 13 };
+                        steps += 1;
+                    }
+                }
+                13 => {
+                    // 13: add [27], -1, [27]
+                    {
+                        let a: i64 = *vm.memory.entry(27).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(27, a + b);
+                    }
+                    // 17: jt [27], 0
+                    {
+                        let cond: i64 = *vm.memory.entry(27).or_insert(0);
+                        let target: i64 = 0;
+                        ip = if cond != 0 { target as usize } else { 20 };
+                        steps += 1;
+                    }
+                }
+                20 => {
+                    // 20: eq [rb-1], [27], [32]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + -1) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(27).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(32, (a == b) as i64);
+                    }
+                    // 24: out [35]
+                    {
+                        stdout.push(*vm.memory.entry(35).or_insert(0));
+                        steps += 1;
+                    }
+                    // 26: halt
+                    vm.set_ip(26);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod generated_31 {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 120] = [204, 59, 1005, 116, 5, 1001, 107, -1, 107, 1005, 107, 0, 1106, -4, 79, 20001, 118, 113, 114, 1008, 116, -15, 112, 1005, 115, 26, 1001, 108, -1, 108, 1005, 108, 19, 3, 118, 1001, 109, -1, 109, 1005, 109, 33, 20208, 6, 112, 113, 4, 113, 109, 3, 207, 13, 118, 118, 1001, 110, -1, 110, 1005, 110, 46, 1106, -14, 64, 1001, 111, -1, 111, 1005, 111, 61, 3, 112, 109, 0, 21007, 117, 2, 112, 1005, 117, 90, 20008, 109, 18, 113, 21008, 116, 18, 118, 22008, 112, 59, 116, 109, 0, 104, -3, 21108, -14, 13, 115, 20008, 119, 115, 118, 99, 2, 4, 2, 4, 1, 7, 5, 8, -8, 4, 9, -3, 4];
+    const COMPILED: [bool; 120] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false, false, false, false, false, false, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: out [rb+59]
+                    {
+                        stdout.push(*vm.memory.entry((rb + 59) as usize).or_insert(0));
+                        steps += 1;
+                    }
+                    // 2: jt [116], 5
+                    {
+                        let cond: i64 = *vm.memory.entry(116).or_insert(0);
+                        let target: i64 = 5;
+                        ip = if cond != 0 { target as usize } else { 5 };
+                        steps += 1;
+                    }
+                }
+                5 => {
+                    // 5: add [107], -1, [107]
+                    {
+                        let a: i64 = *vm.memory.entry(107).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(107, a + b);
+                    }
+                    // 9: jt [107], 0
+                    {
+                        let cond: i64 = *vm.memory.entry(107).or_insert(0);
+                        let target: i64 = 0;
+                        ip = if cond != 0 { target as usize } else { 12 };
+                        steps += 1;
+                    }
+                }
+                12 => {
+                    // 12: jf -4, 79
+                    {
+                        let cond: i64 = -4;
+                        let target: i64 = 79;
+                        ip = if cond == 0 { target as usize } else { 15 };
+                        steps += 1;
+                    }
+                }
+                15 => {
+                    // 15: add [118], [113], [rb+114]
+                    {
+                        let a: i64 = *vm.memory.entry(118).or_insert(0);
+                        let b: i64 = *vm.memory.entry(113).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 114) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 19, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 19;
+                }
+                19 => {
+                    // 19: eq [116], -15, [112]
+                    {
+                        let a: i64 = *vm.memory.entry(116).or_insert(0);
+                        let b: i64 = -15;
+                        steps += 1;
+                        vm.memory.insert(112, (a == b) as i64);
+                    }
+                    // 23: jt [115], 26
+                    {
+                        let cond: i64 = *vm.memory.entry(115).or_insert(0);
+                        let target: i64 = 26;
+                        ip = if cond != 0 { target as usize } else { 26 };
+                        steps += 1;
+                    }
+                }
+                26 => {
+                    // 26: add [108], -1, [108]
+                    {
+                        let a: i64 = *vm.memory.entry(108).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(108, a + b);
+                    }
+                    // 30: jt [108], 19
+                    {
+                        let cond: i64 = *vm.memory.entry(108).or_insert(0);
+                        let target: i64 = 19;
+                        ip = if cond != 0 { target as usize } else { 33 };
+                        steps += 1;
+                    }
+                }
+                33 => {
+                    // 33: in [118]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(33);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(118, value);
+                    }
+                    // 35: add [109], -1, [109]
+                    {
+                        let a: i64 = *vm.memory.entry(109).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(109, a + b);
+                    }
+                    // 39: jt [109], 33
+                    {
+                        let cond: i64 = *vm.memory.entry(109).or_insert(0);
+                        let target: i64 = 33;
+                        ip = if cond != 0 { target as usize } else { 42 };
+                        steps += 1;
+                    }
+                }
+                42 => {
+                    // 42: eq [rb+6], [112], [rb+113]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 6) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(112).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 113) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 46, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 46;
+                }
+                46 => {
+                    // 46: out [113]
+                    {
+                        stdout.push(*vm.memory.entry(113).or_insert(0));
+                        steps += 1;
+                    }
+                    // 48: arb 3
+                    {
+                        rb += 3;
+                        steps += 1;
+                    }
+                    // 50: lt [rb+13], [118], [118]
+                    {
+                        let a: i64 = *vm.memory.entry((rb + 13) as usize).or_insert(0);
+                        let b: i64 = *vm.memory.entry(118).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(118, (a < b) as i64);
+                    }
+                    // 54: add [110], -1, [110]
+                    {
+                        let a: i64 = *vm.memory.entry(110).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(110, a + b);
+                    }
+                    // 58: jt [110], 46
+                    {
+                        let cond: i64 = *vm.memory.entry(110).or_insert(0);
+                        let target: i64 = 46;
+                        ip = if cond != 0 { target as usize } else { 61 };
+                        steps += 1;
+                    }
+                }
+                61 => {
+                    // 61: jf -14, 64
+                    {
+                        let cond: i64 = -14;
+                        let target: i64 = 64;
+                        ip = if cond == 0 { target as usize } else { 64 };
+                        steps += 1;
+                    }
+                }
+                64 => {
+                    // 64: add [111], -1, [111]
+                    {
+                        let a: i64 = *vm.memory.entry(111).or_insert(0);
+                        let b: i64 = -1;
+                        steps += 1;
+                        vm.memory.insert(111, a + b);
+                    }
+                    // 68: jt [111], 61
+                    {
+                        let cond: i64 = *vm.memory.entry(111).or_insert(0);
+                        let target: i64 = 61;
+                        ip = if cond != 0 { target as usize } else { 71 };
+                        steps += 1;
+                    }
+                }
+                71 => {
+                    // 71: in [112]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(71);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(112, value);
+                    }
+                    // 73: arb 0
+                    {
+                        rb += 0;
+                        steps += 1;
+                    }
+                    // 75: lt [117], 2, [rb+112]
+                    {
+                        let a: i64 = *vm.memory.entry(117).or_insert(0);
+                        let b: i64 = 2;
+                        steps += 1;
+                        let addr = (rb + 112) as usize;
+                        vm.memory.insert(addr, (a < b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 79, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 79: jt [117], 90
+                    {
+                        let cond: i64 = *vm.memory.entry(117).or_insert(0);
+                        let target: i64 = 90;
+                        ip = if cond != 0 { target as usize } else { 82 };
+                        steps += 1;
+                    }
+                }
+                82 => {
+                    // 82: eq [109], [18], [rb+113]
+                    {
+                        let a: i64 = *vm.memory.entry(109).or_insert(0);
+                        let b: i64 = *vm.memory.entry(18).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 113) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 86, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 86: eq [116], 18, [rb+118]
+                    {
+                        let a: i64 = *vm.memory.entry(116).or_insert(0);
+                        let b: i64 = 18;
+                        steps += 1;
+                        let addr = (rb + 118) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 90, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    ip = 90;
+                }
+                90 => {
+                    // 90: eq [112], [rb+59], [rb+116]
+                    {
+                        let a: i64 = *vm.memory.entry(112).or_insert(0);
+                        let b: i64 = *vm.memory.entry((rb + 59) as usize).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 116) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 94, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 94: arb 0
+                    {
+                        rb += 0;
+                        steps += 1;
+                    }
+                    // 96: out -3
+                    {
+                        stdout.push(-3);
+                        steps += 1;
+                    }
+                    // 98: eq -14, 13, [rb+115]
+                    {
+                        let a: i64 = -14;
+                        let b: i64 = 13;
+                        steps += 1;
+                        let addr = (rb + 115) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 102, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 102: eq [119], [115], [rb+118]
+                    {
+                        let a: i64 = *vm.memory.entry(119).or_insert(0);
+                        let b: i64 = *vm.memory.entry(115).or_insert(0);
+                        steps += 1;
+                        let addr = (rb + 118) as usize;
+                        vm.memory.insert(addr, (a == b) as i64);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 106, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 106: halt
+                    vm.set_ip(106);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
+
+pub const GENERATED: [(u64, Compiled); 32] = [
+    (0, generated_0::execute),
+    (1, generated_1::execute),
+    (2, generated_2::execute),
+    (3, generated_3::execute),
+    (4, generated_4::execute),
+    (5, generated_5::execute),
+    (6, generated_6::execute),
+    (7, generated_7::execute),
+    (8, generated_8::execute),
+    (9, generated_9::execute),
+    (10, generated_10::execute),
+    (11, generated_11::execute),
+    (12, generated_12::execute),
+    (13, generated_13::execute),
+    (14, generated_14::execute),
+    (15, generated_15::execute),
+    (16, generated_16::execute),
+    (17, generated_17::execute),
+    (18, generated_18::execute),
+    (19, generated_19::execute),
+    (20, generated_20::execute),
+    (21, generated_21::execute),
+    (22, generated_22::execute),
+    (23, generated_23::execute),
+    (24, generated_24::execute),
+    (25, generated_25::execute),
+    (26, generated_26::execute),
+    (27, generated_27::execute),
+    (28, generated_28::execute),
+    (29, generated_29::execute),
+    (30, generated_30::execute),
+    (31, generated_31::execute),
+];
@@ -0,0 +1,96 @@
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod overwritten {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 9] = [109, 4, 21101, 0, 9, 3, 104, 7, 99];
+    const COMPILED: [bool; 9] = [true, true, true, true, true, true, true, true, true];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: arb 4
+                    {
+                        rb += 4;
+                        steps += 1;
+                    }
+                    // 2: add 0, 9, [rb+3]
+                    {
+                        let a: i64 = 0;
+                        let b: i64 = 9;
+                        steps += 1;
+                        let addr = (rb + 3) as usize;
+                        vm.memory.insert(addr, a + b);
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, 6, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    // 6: out 7
+                    {
+                        stdout.push(7);
+                        steps += 1;
+                    }
+                    // 8: halt
+                    vm.set_ip(8);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
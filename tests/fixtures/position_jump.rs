@@ -0,0 +1,112 @@
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod position_jump {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 16] = [3, 12, 6, 12, 15, 1, 13, 14, 13, 4, 13, 99, -1, 0, 1, 9];
+    const COMPILED: [bool; 16] = [true, true, true, true, true, true, true, true, true, true, true, true, false, false, false, false];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: in [12]
+                    {
+                        let value = match stdin.get(pos) {
+                            Some(&value) => value,
+                            None => {
+                                vm.set_ip(0);
+                                vm.set_rel_base(rb);
+                                vm.set_steps(steps);
+                                vm.waiting_for_input = true;
+                                return stdout;
+                            }
+                        };
+                        pos += 1;
+                        steps += 1;
+                        vm.memory.insert(12, value);
+                    }
+                    // 2: jf [12], [15]
+                    {
+                        let cond: i64 = *vm.memory.entry(12).or_insert(0);
+                        let target: i64 = *vm.memory.entry(15).or_insert(0);
+                        ip = if cond == 0 { target as usize } else { 5 };
+                        steps += 1;
+                    }
+                }
+                5 => {
+                    // 5: add [13], [14], [13]
+                    {
+                        let a: i64 = *vm.memory.entry(13).or_insert(0);
+                        let b: i64 = *vm.memory.entry(14).or_insert(0);
+                        steps += 1;
+                        vm.memory.insert(13, a + b);
+                    }
+                    // 9: out [13]
+                    {
+                        stdout.push(*vm.memory.entry(13).or_insert(0));
+                        steps += 1;
+                    }
+                    // 11: halt
+                    vm.set_ip(11);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}
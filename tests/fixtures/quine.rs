@@ -0,0 +1,108 @@
+// Generated by intcode2rust. Do not edit.
+#[allow(clippy::all, unused_assignments, unused_mut, unused_variables)]
+pub mod quine {
+    use super::{IntcodeComputer, Step};
+
+    const CODE: [i64; 16] = [109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99];
+    const COMPILED: [bool; 16] = [true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true];
+
+    fn intact(vm: &IntcodeComputer) -> bool {
+        (0..CODE.len()).all(|i| !COMPILED[i] || vm.memory.get(&i) == Some(&CODE[i]))
+    }
+
+    fn interpret(
+        vm: &mut IntcodeComputer,
+        ip: usize,
+        rb: i64,
+        steps: usize,
+        stdin: &[i64],
+        mut stdout: Vec<i64>,
+    ) -> Vec<i64> {
+        vm.set_ip(ip);
+        vm.set_rel_base(rb);
+        vm.set_steps(steps);
+        stdout.extend(vm.execute(stdin));
+        stdout
+    }
+
+    pub fn execute(vm: &mut IntcodeComputer, stdin: &[i64]) -> Vec<i64> {
+        if vm.journal().is_some() || vm.recording().is_some() || !intact(vm) {
+            return vm.execute(stdin);
+        }
+        vm.waiting_for_input = vm.waiting_for_input && stdin.is_empty();
+        let mut stdout = Vec::new();
+        let mut ip = vm.ip();
+        let mut rb = vm.rel_base();
+        let mut steps = vm.steps();
+        let mut pos = 0;
+        loop {
+            match ip {
+                0 => {
+                    // 0: arb 1
+                    {
+                        rb += 1;
+                        steps += 1;
+                    }
+                    // 2: out [rb-1]
+                    {
+                        stdout.push(*vm.memory.entry((rb + -1) as usize).or_insert(0));
+                        steps += 1;
+                    }
+                    // 4: add [100], 1, [100]
+                    {
+                        let a: i64 = *vm.memory.entry(100).or_insert(0);
+                        let b: i64 = 1;
+                        steps += 1;
+                        vm.memory.insert(100, a + b);
+                    }
+                    // 8: eq [100], 16, [101]
+                    {
+                        let a: i64 = *vm.memory.entry(100).or_insert(0);
+                        let b: i64 = 16;
+                        steps += 1;
+                        vm.memory.insert(101, (a == b) as i64);
+                    }
+                    // 12: jf [101], 0
+                    {
+                        let cond: i64 = *vm.memory.entry(101).or_insert(0);
+                        let target: i64 = 0;
+                        ip = if cond == 0 { target as usize } else { 15 };
+                        steps += 1;
+                    }
+                }
+                15 => {
+                    // 15: halt
+                    vm.set_ip(15);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    vm.done = true;
+                    return stdout;
+                }
+                _ => {
+                    vm.set_ip(ip);
+                    vm.set_rel_base(rb);
+                    vm.set_steps(steps);
+                    // interpreted code may overwrite compiled code as well
+                    if let Some(addr) = vm.store_addr() {
+                        if addr < CODE.len() && COMPILED[addr] {
+                            return interpret(vm, ip, rb, steps, &stdin[pos..], stdout);
+                        }
+                    }
+                    match vm.step(stdin.get(pos).copied()) {
+                        Step::Continue => (),
+                        Step::Input => pos += 1,
+                        Step::Output(value) => stdout.push(value),
+                        Step::WaitingForInput => {
+                            vm.waiting_for_input = true;
+                            return stdout;
+                        }
+                        Step::Halted => return stdout,
+                    }
+                    ip = vm.ip();
+                    rb = vm.rel_base();
+                    steps = vm.steps();
+                }
+            }
+        }
+    }
+}